@@ -0,0 +1,60 @@
+use std::{error, fmt, io};
+
+#[derive(Debug)]
+pub enum ApiKeyError {
+    /// Returned when the environment variable holding the API key is not set.
+    EnvVarNotPresent(&'static str),
+
+    /// Returned when the environment variable holding the API key is not valid unicode.
+    ///
+    /// The variable's value is not kept, as it may hold the API key.
+    EnvVarNotUnicode(&'static str),
+
+    /// Returned when the API key file could not be read.
+    Io(io::Error),
+
+    /// Returned when the API key is empty, or only whitespace.
+    Empty,
+
+    /// Returned when the API key holds characters that can't be sent in an HTTP header.
+    InvalidHeaderValue,
+
+    /// Returned when an `ApiKeyPool` is created without any key.
+    EmptyPool,
+}
+
+impl error::Error for ApiKeyError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::EnvVarNotPresent(_)
+            | Self::EnvVarNotUnicode(_)
+            | Self::Empty
+            | Self::InvalidHeaderValue
+            | Self::EmptyPool => None,
+        }
+    }
+}
+
+impl fmt::Display for ApiKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EnvVarNotPresent(name) => write!(f, "environment variable {name} is not set"),
+            Self::EnvVarNotUnicode(name) => {
+                write!(f, "environment variable {name} is not valid unicode")
+            }
+            Self::Io(e) => write!(f, "failed to read API key file: {e}"),
+            Self::Empty => write!(f, "API key must not be empty"),
+            Self::InvalidHeaderValue => {
+                write!(f, "API key holds characters that can't be sent in a header")
+            }
+            Self::EmptyPool => write!(f, "an API key pool needs at least one key"),
+        }
+    }
+}
+
+impl From<io::Error> for ApiKeyError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
@@ -0,0 +1,245 @@
+use crate::api_key::{ApiKey, ApiKeyError};
+use crate::error::{ApiErrorCode, Error};
+use crate::response_meta::RateLimit;
+use std::cmp::Reverse;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Default time a key is left unused after Yelp rejected it.
+pub const DEFAULT_BENCH_DURATION: Duration = Duration::from_secs(60 * 60);
+
+/// Several API keys used by a single client.
+///
+/// Every request is sent with the key that has the most daily quota left (according to the
+/// `RateLimit-Remaining` header of its latest response, until the `RateLimit-ResetTime` it was
+/// sent along with). Keys that Yelp answers with `ACCESS_LIMIT_REACHED` or `TOKEN_INVALID` are
+/// benched (left unused) for a while, but never past the reset time of their quota.
+///
+/// Clones share the same state, so a pool can be shared between clones of a client, or even
+/// between several clients.
+#[derive(Debug, Clone)]
+pub struct ApiKeyPool {
+    state: Arc<Mutex<ApiKeyPoolState>>,
+}
+
+#[derive(Debug)]
+struct ApiKeyPoolState {
+    keys: Vec<PooledApiKey>,
+
+    /// How long a rejected key is left unused.
+    bench_duration: Duration,
+}
+
+#[derive(Debug)]
+struct PooledApiKey {
+    api_key: ApiKey,
+    requests: u64,
+    failures: u64,
+    rate_limit: Option<RateLimit>,
+    benched_until: Option<Instant>,
+    last_error_code: Option<ApiErrorCode>,
+}
+
+/// Usage statistics of one key of an `ApiKeyPool`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeyStats {
+    /// Position of the key in the list the pool was created with.
+    pub index: usize,
+
+    /// Number of requests sent with the key.
+    pub requests: u64,
+
+    /// Number of those requests that failed.
+    pub failures: u64,
+
+    /// Rate limit information of the latest response to a request sent with the key.
+    pub rate_limit: Option<RateLimit>,
+
+    /// How long the key is still benched for, if it is.
+    pub benched_for: Option<Duration>,
+
+    /// Error code of the latest failed request sent with the key.
+    pub last_error_code: Option<ApiErrorCode>,
+}
+
+impl ApiKeyPool {
+    /// # Errors
+    ///
+    /// Returns `ApiKeyError::EmptyPool` if `api_keys` is empty.
+    pub fn new<K: Into<ApiKey>, I: IntoIterator<Item = K>>(
+        api_keys: I,
+    ) -> Result<Self, ApiKeyError> {
+        let keys: Vec<PooledApiKey> = api_keys
+            .into_iter()
+            .map(|api_key| PooledApiKey {
+                api_key: api_key.into(),
+                requests: 0,
+                failures: 0,
+                rate_limit: None,
+                benched_until: None,
+                last_error_code: None,
+            })
+            .collect();
+        if keys.is_empty() {
+            return Err(ApiKeyError::EmptyPool);
+        }
+
+        Ok(Self {
+            state: Arc::new(Mutex::new(ApiKeyPoolState {
+                keys,
+                bench_duration: DEFAULT_BENCH_DURATION,
+            })),
+        })
+    }
+
+    /// Leaves keys rejected by Yelp unused for `bench_duration` instead of
+    /// `DEFAULT_BENCH_DURATION`.
+    #[must_use]
+    pub fn with_bench_duration(self, bench_duration: Duration) -> Self {
+        self.lock().bench_duration = bench_duration;
+        self
+    }
+
+    /// Number of keys in the pool.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().keys.len()
+    }
+
+    /// Always false, since a pool holds at least one key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().keys.is_empty()
+    }
+
+    /// The first key of the pool.
+    pub(crate) fn first_key(&self) -> ApiKey {
+        self.lock().keys[0].api_key.clone()
+    }
+
+    /// Usage statistics of every key, in the order the pool was created with.
+    #[must_use]
+    pub fn stats(&self) -> Vec<ApiKeyStats> {
+        let now: Instant = Instant::now();
+        self.lock()
+            .keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| ApiKeyStats {
+                index,
+                requests: key.requests,
+                failures: key.failures,
+                rate_limit: key.rate_limit.clone(),
+                benched_for: key
+                    .benched_until
+                    .filter(|benched_until| *benched_until > now)
+                    .map(|benched_until| benched_until - now),
+                last_error_code: key.last_error_code.clone(),
+            })
+            .collect()
+    }
+
+    /// Picks the key to send the next request with, returning its index and the key itself.
+    ///
+    /// Keys with unknown remaining quota (including keys whose quota has reset since their latest
+    /// response) are preferred, so that every key gets probed, then ties are broken by using the
+    /// key that sent the fewest requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::AllApiKeysBenched` if every key is benched.
+    pub(crate) fn select(&self) -> Result<(usize, ApiKey), Error> {
+        let mut state = self.lock();
+        let now: Instant = Instant::now();
+        let system_now: SystemTime = SystemTime::now();
+
+        let Some((index, key)) = state
+            .keys
+            .iter_mut()
+            .enumerate()
+            // prefer the first of equally good keys
+            .rev()
+            .filter(|(_, key)| {
+                key.benched_until
+                    .is_none_or(|benched_until| benched_until <= now)
+            })
+            .max_by_key(|(_, key)| (key.remaining(system_now), Reverse(key.requests)))
+        else {
+            let available_in: Duration = state
+                .keys
+                .iter()
+                .filter_map(|key| key.benched_until)
+                .min()
+                .map_or(Duration::ZERO, |benched_until| benched_until - now);
+            return Err(Error::AllApiKeysBenched { available_in });
+        };
+
+        key.benched_until = None;
+        key.requests += 1;
+        Ok((index, key.api_key.clone()))
+    }
+
+    /// Records the outcome of a request sent with the key at `index`.
+    pub(crate) fn record(
+        &self,
+        index: usize,
+        rate_limit: Option<&RateLimit>,
+        error: Option<&Error>,
+    ) {
+        let mut state = self.lock();
+        let bench_duration: Duration = state.bench_duration;
+        let Some(key) = state.keys.get_mut(index) else {
+            return;
+        };
+
+        if let Some(rate_limit) = rate_limit {
+            key.rate_limit = Some(rate_limit.clone());
+        }
+        if let Some(error) = error {
+            key.failures += 1;
+            if let Some(code) = error.api_error_code() {
+                key.last_error_code = Some(code.clone());
+            }
+            if is_bench_error(error) {
+                let now: Instant = Instant::now();
+                let mut benched_until: Instant = now + bench_duration;
+                // an exhausted quota is usable again as soon as it resets
+                if error.api_error_code() == Some(&ApiErrorCode::AccessLimitReached) {
+                    if let Some(reset_in) = key
+                        .rate_limit
+                        .as_ref()
+                        .and_then(RateLimit::reset_at)
+                        .and_then(|reset_at| reset_at.duration_since(SystemTime::now()).ok())
+                    {
+                        benched_until = benched_until.min(now + reset_in);
+                    }
+                }
+                key.benched_until = Some(benched_until);
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ApiKeyPoolState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl PooledApiKey {
+    /// Daily quota left according to the key's latest response, or `u64::MAX` if unknown or if
+    /// the quota has reset since.
+    fn remaining(&self, now: SystemTime) -> u64 {
+        self.rate_limit
+            .as_ref()
+            .filter(|rate_limit| rate_limit.reset_at().is_none_or(|reset_at| reset_at > now))
+            .and_then(|rate_limit| rate_limit.remaining)
+            .unwrap_or(u64::MAX)
+    }
+}
+
+/// Whether `error` means the key it was sent with shouldn't be used for a while.
+pub(crate) const fn is_bench_error(error: &Error) -> bool {
+    matches!(
+        error.api_error_code(),
+        Some(ApiErrorCode::AccessLimitReached | ApiErrorCode::TokenInvalid)
+    )
+}
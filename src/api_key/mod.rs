@@ -0,0 +1,83 @@
+mod api_key_error;
+mod api_key_pool;
+
+pub use api_key_error::*;
+pub use api_key_pool::*;
+
+use std::fmt;
+use std::path::Path;
+use std::{env, fs};
+use zeroize::Zeroizing;
+
+/// Environment variable read by `ApiKey::from_env`.
+pub const API_KEY_ENV_VAR: &str = "YELP_FUSION_API_KEY";
+
+/// Yelp Fusion API key.
+///
+/// The key is redacted from `Debug` output and its memory is zeroed when dropped.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ApiKey(Zeroizing<String>);
+
+impl ApiKey {
+    #[must_use]
+    pub fn new(api_key: String) -> Self {
+        Self(Zeroizing::new(api_key))
+    }
+
+    /// Reads the API key from the `YELP_FUSION_API_KEY` environment variable.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the environment variable is not set, is not valid unicode, or is
+    /// empty.
+    pub fn from_env() -> Result<Self, ApiKeyError> {
+        let api_key: Zeroizing<String> =
+            Zeroizing::new(env::var(API_KEY_ENV_VAR).map_err(|e| match e {
+                env::VarError::NotPresent => ApiKeyError::EnvVarNotPresent(API_KEY_ENV_VAR),
+                env::VarError::NotUnicode(_) => ApiKeyError::EnvVarNotUnicode(API_KEY_ENV_VAR),
+            })?);
+        Self::parse(&api_key)
+    }
+
+    /// Reads the API key from the file at `path`, ignoring surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file could not be read, or if it is empty.
+    pub fn from_key_file<P: AsRef<Path>>(path: P) -> Result<Self, ApiKeyError> {
+        let api_key: Zeroizing<String> = Zeroizing::new(fs::read_to_string(path)?);
+        Self::parse(&api_key)
+    }
+
+    fn parse(api_key: &str) -> Result<Self, ApiKeyError> {
+        let api_key: &str = api_key.trim();
+        if api_key.is_empty() {
+            return Err(ApiKeyError::Empty);
+        }
+        Ok(Self::new(api_key.to_string()))
+    }
+
+    /// Returns the API key in plain text.
+    #[must_use]
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for ApiKey {
+    fn from(api_key: String) -> Self {
+        Self::new(api_key)
+    }
+}
+
+impl From<&str> for ApiKey {
+    fn from(api_key: &str) -> Self {
+        Self::new(api_key.to_string())
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ApiKey([REDACTED])")
+    }
+}
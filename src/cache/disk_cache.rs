@@ -0,0 +1,99 @@
+use crate::cache::{CacheBackend, stable_hash};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Number of temporary files written by this process, to give every write its own file.
+static TEMPORARY_FILES: AtomicU64 = AtomicU64::new(0);
+
+/// `CacheBackend` persisting every response as a JSON file in a directory, so that the cache
+/// survives restarts and can be shared between processes.
+///
+/// Expired files are only removed when they're read.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    directory: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiskCacheEntry {
+    /// Key of the entry, to tell apart keys with the same file name.
+    key: String,
+
+    /// When the entry expires, as milliseconds since the Unix epoch.
+    expires_at_millis: u64,
+
+    body: String,
+}
+
+impl DiskCache {
+    /// # Errors
+    ///
+    /// Will return `Err` if `directory` could not be created.
+    pub fn new<P: Into<PathBuf>>(directory: P) -> io::Result<Self> {
+        let directory: PathBuf = directory.into();
+        fs::create_dir_all(&directory)?;
+        Ok(Self { directory })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory
+            .join(format!("{:016x}.json", stable_hash(key)))
+    }
+}
+
+impl CacheBackend for DiskCache {
+    fn get(&self, key: &str) -> Option<Bytes> {
+        let path: PathBuf = self.path(key);
+        let entry: DiskCacheEntry = serde_json::from_slice(&fs::read(&path).ok()?).ok()?;
+        if entry.key != key {
+            return None;
+        }
+        if entry.expires_at_millis <= unix_now_millis() {
+            // another process may have removed it already
+            let _ = fs::remove_file(path);
+            return None;
+        }
+        Some(Bytes::from(entry.body))
+    }
+
+    fn insert(&self, key: &str, body: Bytes, ttl: Duration) {
+        // Yelp only returns JSON, so bodies that aren't UTF-8 are simply not cached
+        let Ok(body) = String::from_utf8(body.to_vec()) else {
+            return;
+        };
+        let entry: DiskCacheEntry = DiskCacheEntry {
+            key: key.to_string(),
+            expires_at_millis: unix_now_millis()
+                .saturating_add(u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX)),
+            body,
+        };
+        let Ok(contents) = serde_json::to_vec(&entry) else {
+            return;
+        };
+
+        // write to a temporary file first, so that readers never see a partial entry
+        let path: PathBuf = self.path(key);
+        let temporary_path: PathBuf = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed)
+        ));
+        if fs::write(&temporary_path, contents).is_err()
+            || fs::rename(&temporary_path, &path).is_err()
+        {
+            let _ = fs::remove_file(temporary_path);
+        }
+    }
+}
+
+fn unix_now_millis() -> u64 {
+    let now: Duration = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    u64::try_from(now.as_millis()).unwrap_or(u64::MAX)
+}
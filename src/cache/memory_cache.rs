@@ -0,0 +1,119 @@
+use crate::cache::CacheBackend;
+use bytes::Bytes;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// In-memory `CacheBackend` holding at most `capacity` responses, evicting the least recently
+/// used one when full.
+#[derive(Debug)]
+pub struct MemoryCache {
+    state: Mutex<MemoryCacheState>,
+}
+
+#[derive(Debug)]
+struct MemoryCacheState {
+    capacity: usize,
+    entries: HashMap<String, MemoryCacheEntry>,
+
+    /// Keys of `entries` by when they were last used.
+    recency: BTreeMap<u64, String>,
+
+    /// Incremented on every use of an entry.
+    tick: u64,
+}
+
+#[derive(Debug)]
+struct MemoryCacheEntry {
+    body: Bytes,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+impl MemoryCache {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(MemoryCacheState {
+                capacity,
+                entries: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+
+    /// Number of cached responses, including expired ones that haven't been evicted yet.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MemoryCacheState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn get(&self, key: &str) -> Option<Bytes> {
+        let mut state = self.lock();
+        let entry: &MemoryCacheEntry = state.entries.get(key)?;
+        if entry.expires_at <= Instant::now() {
+            state.remove(key);
+            return None;
+        }
+
+        let (last_used, body) = (entry.last_used, entry.body.clone());
+        let tick: u64 = state.next_tick();
+        state.recency.remove(&last_used);
+        state.recency.insert(tick, key.to_string());
+        if let Some(entry) = state.entries.get_mut(key) {
+            entry.last_used = tick;
+        }
+        Some(body)
+    }
+
+    fn insert(&self, key: &str, body: Bytes, ttl: Duration) {
+        let mut state = self.lock();
+        if state.capacity == 0 {
+            return;
+        }
+
+        state.remove(key);
+        while state.entries.len() >= state.capacity {
+            let Some((_, least_recently_used)) = state.recency.pop_first() else {
+                break;
+            };
+            state.entries.remove(&least_recently_used);
+        }
+
+        let tick: u64 = state.next_tick();
+        state.recency.insert(tick, key.to_string());
+        state.entries.insert(
+            key.to_string(),
+            MemoryCacheEntry {
+                body,
+                expires_at: Instant::now() + ttl,
+                last_used: tick,
+            },
+        );
+    }
+}
+
+impl MemoryCacheState {
+    const fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.last_used);
+        }
+    }
+}
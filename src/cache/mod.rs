@@ -0,0 +1,154 @@
+mod disk_cache;
+mod memory_cache;
+
+pub use disk_cache::*;
+pub use memory_cache::*;
+
+use crate::endpoints::Endpoint;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default time a response stays cached.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Storage of cached response bodies.
+///
+/// Implementations must only return entries that haven't expired yet.
+pub trait CacheBackend: Debug + Send + Sync {
+    /// Returns the body cached under `key`, if it hasn't expired.
+    fn get(&self, key: &str) -> Option<Bytes>;
+
+    /// Caches `body` under `key` for `ttl`.
+    fn insert(&self, key: &str, body: Bytes, ttl: Duration);
+}
+
+/// Cache of successful `GET` responses, keyed by base URL, endpoint path and canonicalized query
+/// params.
+///
+/// Cache hits don't send a request, so they don't use up any quota; they're flagged by
+/// `ResponseMeta::cache_hit`.
+///
+/// Clones share the same backend, so a cache can be shared between clones of a client, or even
+/// between several clients.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    backend: Arc<dyn CacheBackend>,
+
+    /// How long responses of endpoints without a specific TTL stay cached.
+    default_ttl: Duration,
+
+    /// How long responses of each endpoint stay cached.
+    ttls: HashMap<Endpoint, Duration>,
+}
+
+impl ResponseCache {
+    #[must_use]
+    pub fn new<B: CacheBackend + 'static>(backend: B) -> Self {
+        Self {
+            backend: Arc::new(backend),
+            default_ttl: DEFAULT_CACHE_TTL,
+            ttls: HashMap::new(),
+        }
+    }
+
+    /// In-memory cache holding at most `capacity` responses, evicting the least recently used.
+    #[must_use]
+    pub fn in_memory(capacity: usize) -> Self {
+        Self::new(MemoryCache::new(capacity))
+    }
+
+    /// Cache persisting responses as files in `directory`, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `directory` could not be created.
+    pub fn on_disk<P: Into<PathBuf>>(directory: P) -> io::Result<Self> {
+        Ok(Self::new(DiskCache::new(directory)?))
+    }
+
+    /// Caches responses of endpoints without a specific TTL for `ttl` instead of
+    /// `DEFAULT_CACHE_TTL`.
+    #[must_use]
+    pub const fn with_default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    /// Caches responses of `endpoint` for `ttl`. A zero `ttl` disables caching of `endpoint`.
+    #[must_use]
+    pub fn with_ttl(mut self, endpoint: Endpoint, ttl: Duration) -> Self {
+        self.ttls.insert(endpoint, ttl);
+        self
+    }
+
+    /// How long responses of `endpoint` stay cached.
+    #[must_use]
+    pub fn ttl(&self, endpoint: Endpoint) -> Duration {
+        self.ttls
+            .get(&endpoint)
+            .copied()
+            .unwrap_or(self.default_ttl)
+    }
+
+    /// Key of a `GET` request to `path` of `base_url` with `query_params`, independent of the
+    /// order of the params.
+    ///
+    /// The base URL is part of the key, so that a cache shared between clients of different
+    /// servers (e.g. Yelp and a mock server) never mixes up their responses.
+    #[must_use]
+    pub fn key(base_url: &str, path: &str, query_params: &[(&str, String)]) -> String {
+        format!(
+            "{}{}",
+            base_url.trim_end_matches('/'),
+            Self::path_key(path, query_params)
+        )
+    }
+
+    /// Key of a `GET` request to `path` with `query_params`, without the base URL.
+    pub(crate) fn path_key(path: &str, query_params: &[(&str, String)]) -> String {
+        let mut query_params: Vec<&(&str, String)> = query_params.iter().collect();
+        query_params.sort();
+
+        let query: Vec<String> = query_params
+            .iter()
+            .map(|(key, value)| format!("{}={}", escape(key), escape(value)))
+            .collect();
+        format!("{path}?{}", query.join("&"))
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<Bytes> {
+        self.backend.get(key)
+    }
+
+    /// Caches the response `body` of a request to `path`, unless caching is disabled for its
+    /// endpoint.
+    pub(crate) fn insert(&self, path: &str, key: &str, body: Bytes) {
+        let ttl: Duration =
+            Endpoint::from_path(path).map_or(self.default_ttl, |endpoint| self.ttl(endpoint));
+        if !ttl.is_zero() {
+            self.backend.insert(key, body, ttl);
+        }
+    }
+}
+
+/// 64-bit FNV-1a hash of `value`, which unlike `DefaultHasher` is stable across runs, platforms
+/// and Rust versions.
+#[must_use]
+pub fn stable_hash(value: &str) -> u64 {
+    value.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Escapes the characters delimiting query params, so that keys are unambiguous.
+fn escape(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('&', "%26")
+        .replace('=', "%3D")
+}
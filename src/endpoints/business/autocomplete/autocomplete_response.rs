@@ -0,0 +1,18 @@
+use crate::models::{AutocompleteBusiness, AutocompleteTerm, Category};
+use serde::{Deserialize, Serialize};
+
+/// Response body from Autocomplete request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutocompleteResponse {
+    /// List of search terms that match the input text.
+    pub terms: Vec<AutocompleteTerm>,
+
+    /// List of businesses that match the input text.
+    ///
+    /// Only returned when latitude and longitude are provided.
+    #[serde(default)]
+    pub businesses: Vec<AutocompleteBusiness>,
+
+    /// List of categories that match the input text.
+    pub categories: Vec<Category>,
+}
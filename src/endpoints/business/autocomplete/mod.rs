@@ -0,0 +1,105 @@
+mod autocomplete_response;
+
+use crate::error::Error;
+use crate::models::Coordinates;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use autocomplete_response::*;
+
+impl YelpFusionClient {
+    /// Get autocomplete suggestions for search keywords, businesses and categories, based on the
+    /// input text.
+    ///
+    /// Business suggestions are only returned when `coordinates` are provided.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `AutocompleteResponse`.
+    pub async fn autocomplete(
+        &self,
+        text: &str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> Result<AutocompleteResponse, Error> {
+        self.get("/autocomplete", &to_query_params(text, coordinates, locale))
+            .await
+    }
+
+    /// Same as `autocomplete`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `autocomplete`.
+    pub async fn autocomplete_with_meta(
+        &self,
+        text: &str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> Result<(AutocompleteResponse, ResponseMeta), Error> {
+        self.get_with_meta("/autocomplete", &to_query_params(text, coordinates, locale))
+            .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Get autocomplete suggestions for search keywords, businesses and categories, based on the
+    /// input text.
+    ///
+    /// Business suggestions are only returned when `coordinates` are provided.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `AutocompleteResponse`.
+    pub fn autocomplete(
+        &self,
+        text: &str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> Result<AutocompleteResponse, Error> {
+        self.get("/autocomplete", &to_query_params(text, coordinates, locale))
+    }
+
+    /// Same as `autocomplete`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `autocomplete`.
+    pub fn autocomplete_with_meta(
+        &self,
+        text: &str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> Result<(AutocompleteResponse, ResponseMeta), Error> {
+        self.get_with_meta("/autocomplete", &to_query_params(text, coordinates, locale))
+    }
+}
+
+fn to_query_params(
+    text: &str,
+    coordinates: Option<Coordinates>,
+    locale: Option<String>,
+) -> Vec<(&'static str, String)> {
+    let mut query_params: Vec<(&str, String)> = vec![];
+
+    // text
+    query_params.push(("text", text.to_string()));
+
+    // latitude
+    if let Some(coordinates) = coordinates {
+        query_params.push(("latitude", coordinates.latitude.to_string()));
+        query_params.push(("longitude", coordinates.longitude.to_string()));
+    }
+
+    // locale
+    if let Some(locale) = locale {
+        query_params.push(("locale", locale));
+    }
+
+    query_params
+}
@@ -0,0 +1,212 @@
+use crate::endpoints::{BusinessMatchPayloadBuilder, BusinessMatchPayloadError};
+use crate::models::{Coordinates, MatchThreshold};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessMatchPayload {
+    /// The name of the business.
+    ///
+    /// Maximum length is 64.
+    pub name: String,
+
+    /// The first line of the business’s address.
+    ///
+    /// Maximum length is 64.
+    pub address1: String,
+
+    /// The second line of the business’s address.
+    ///
+    /// Maximum length is 64.
+    pub address2: Option<String>,
+
+    /// The third line of the business’s address.
+    ///
+    /// Maximum length is 64.
+    pub address3: Option<String>,
+
+    /// The city of the business.
+    ///
+    /// Maximum length is 64.
+    pub city: String,
+
+    /// The ISO 3166-2 (with a few exceptions) state code of this business.
+    ///
+    /// Maximum length is 3.
+    pub state: String,
+
+    /// The ISO 3166-1 alpha-2 country code of this business.
+    ///
+    /// Maximum length is 2.
+    pub country: String,
+
+    /// Latitude and longitude of the business.
+    pub coordinates: Option<Coordinates>,
+
+    /// The phone number of the business which can be submitted as (a) locally formatted with
+    /// digits only (e.g., 016703080) or (b) internationally formatted with a leading `+` sign and
+    /// digits only after (+35316703080).
+    ///
+    /// Maximum length is 32.
+    pub phone: Option<String>,
+
+    /// The Zip code of this business.
+    pub zip_code: Option<String>,
+
+    /// Unique Yelp identifier of the business if available.
+    ///
+    /// Used as a hint when finding a matching business.
+    pub yelp_business_id: Option<String>,
+
+    /// Maximum number of businesses to return.
+    ///
+    /// Must be between 1 and 10, defaults to 3.
+    pub limit: Option<usize>,
+
+    /// Specifies whether a match quality threshold should be applied to the matched businesses.
+    ///
+    /// The default is `default`.
+    pub match_threshold: Option<MatchThreshold>,
+}
+
+impl BusinessMatchPayload {
+    /// # Errors
+    ///
+    /// Returns `BusinessMatchPayloadError::FieldTooLong` if `name`, `address1`, `address2`,
+    /// `address3` or `city` are over `64` characters, `state` is over `3` characters, `country` is
+    /// over `2` characters, `phone` is over `32` characters or `yelp_business_id` is over `22`
+    /// characters.
+    ///
+    /// Returns `BusinessMatchPayloadError::LimitOutOfRange` if `limit` is not between `1` and
+    /// `10`.
+    #[expect(clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        address1: String,
+        address2: Option<String>,
+        address3: Option<String>,
+        city: String,
+        state: String,
+        country: String,
+        coordinates: Option<Coordinates>,
+        phone: Option<String>,
+        zip_code: Option<String>,
+        yelp_business_id: Option<String>,
+        limit: Option<usize>,
+        match_threshold: Option<MatchThreshold>,
+    ) -> Result<Self, BusinessMatchPayloadError> {
+        // make sure every field fits within the length Yelp accepts
+        check_length("name", Some(&name), 64)?;
+        check_length("address1", Some(&address1), 64)?;
+        check_length("address2", address2.as_ref(), 64)?;
+        check_length("address3", address3.as_ref(), 64)?;
+        check_length("city", Some(&city), 64)?;
+        check_length("state", Some(&state), 3)?;
+        check_length("country", Some(&country), 2)?;
+        check_length("phone", phone.as_ref(), 32)?;
+        check_length("yelp_business_id", yelp_business_id.as_ref(), 22)?;
+
+        // make sure limit is between `1` and `10` entries
+        if let Some(limit) = limit {
+            if !(1..=10).contains(&limit) {
+                return Err(BusinessMatchPayloadError::LimitOutOfRange(limit));
+            }
+        }
+
+        Ok(Self {
+            name,
+            address1,
+            address2,
+            address3,
+            city,
+            state,
+            country,
+            coordinates,
+            phone,
+            zip_code,
+            yelp_business_id,
+            limit,
+            match_threshold,
+        })
+    }
+
+    #[must_use]
+    pub fn builder() -> BusinessMatchPayloadBuilder {
+        BusinessMatchPayloadBuilder::default()
+    }
+
+    #[must_use]
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut query_params: Vec<(&str, String)> = vec![];
+
+        // name
+        query_params.push(("name", self.name.clone()));
+
+        // address1
+        query_params.push(("address1", self.address1.clone()));
+
+        // address2
+        if let Some(address2) = &self.address2 {
+            query_params.push(("address2", address2.clone()));
+        }
+
+        // address3
+        if let Some(address3) = &self.address3 {
+            query_params.push(("address3", address3.clone()));
+        }
+
+        // city
+        query_params.push(("city", self.city.clone()));
+
+        // state
+        query_params.push(("state", self.state.clone()));
+
+        // country
+        query_params.push(("country", self.country.clone()));
+
+        // latitude
+        if let Some(coordinates) = &self.coordinates {
+            query_params.push(("latitude", coordinates.latitude.to_string()));
+            query_params.push(("longitude", coordinates.longitude.to_string()));
+        }
+
+        // phone
+        if let Some(phone) = &self.phone {
+            query_params.push(("phone", phone.clone()));
+        }
+
+        // zip_code
+        if let Some(zip_code) = &self.zip_code {
+            query_params.push(("zip_code", zip_code.clone()));
+        }
+
+        // yelp_business_id
+        if let Some(yelp_business_id) = &self.yelp_business_id {
+            query_params.push(("yelp_business_id", yelp_business_id.clone()));
+        }
+
+        // limit
+        if let Some(limit) = &self.limit {
+            query_params.push(("limit", limit.to_string()));
+        }
+
+        // match_threshold
+        if let Some(match_threshold) = &self.match_threshold {
+            query_params.push(("match_threshold", match_threshold.to_string()));
+        }
+
+        query_params
+    }
+}
+
+fn check_length(
+    field: &'static str,
+    value: Option<&String>,
+    max_length: usize,
+) -> Result<(), BusinessMatchPayloadError> {
+    match value {
+        Some(value) if value.chars().count() > max_length => {
+            Err(BusinessMatchPayloadError::FieldTooLong { field, max_length })
+        }
+        _ => Ok(()),
+    }
+}
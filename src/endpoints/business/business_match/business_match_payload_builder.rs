@@ -0,0 +1,170 @@
+use crate::endpoints::{BusinessMatchPayload, BusinessMatchPayloadError};
+use crate::models::{Coordinates, MatchThreshold};
+
+#[derive(Debug, Clone, Default)]
+pub struct BusinessMatchPayloadBuilder {
+    /// Required. The name of the business.
+    ///
+    /// Maximum length is 64.
+    pub name: Option<String>,
+
+    /// Required. The first line of the business’s address.
+    ///
+    /// Maximum length is 64.
+    pub address1: Option<String>,
+
+    /// The second line of the business’s address.
+    ///
+    /// Maximum length is 64.
+    pub address2: Option<String>,
+
+    /// The third line of the business’s address.
+    ///
+    /// Maximum length is 64.
+    pub address3: Option<String>,
+
+    /// Required. The city of the business.
+    ///
+    /// Maximum length is 64.
+    pub city: Option<String>,
+
+    /// Required. The ISO 3166-2 (with a few exceptions) state code of this business.
+    ///
+    /// Maximum length is 3.
+    pub state: Option<String>,
+
+    /// Required. The ISO 3166-1 alpha-2 country code of this business.
+    ///
+    /// Maximum length is 2.
+    pub country: Option<String>,
+
+    /// Latitude and longitude of the business.
+    pub coordinates: Option<Coordinates>,
+
+    /// The phone number of the business.
+    ///
+    /// Maximum length is 32.
+    pub phone: Option<String>,
+
+    /// The Zip code of this business.
+    pub zip_code: Option<String>,
+
+    /// Unique Yelp identifier of the business if available.
+    ///
+    /// Used as a hint when finding a matching business.
+    pub yelp_business_id: Option<String>,
+
+    /// Maximum number of businesses to return.
+    ///
+    /// Must be between 1 and 10, defaults to 3.
+    pub limit: Option<usize>,
+
+    /// Specifies whether a match quality threshold should be applied to the matched businesses.
+    ///
+    /// The default is `default`.
+    pub match_threshold: Option<MatchThreshold>,
+}
+
+impl BusinessMatchPayloadBuilder {
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn address1(&mut self, address1: String) -> &mut Self {
+        self.address1 = Some(address1);
+        self
+    }
+
+    pub fn address2(&mut self, address2: String) -> &mut Self {
+        self.address2 = Some(address2);
+        self
+    }
+
+    pub fn address3(&mut self, address3: String) -> &mut Self {
+        self.address3 = Some(address3);
+        self
+    }
+
+    pub fn city(&mut self, city: String) -> &mut Self {
+        self.city = Some(city);
+        self
+    }
+
+    pub fn state(&mut self, state: String) -> &mut Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn country(&mut self, country: String) -> &mut Self {
+        self.country = Some(country);
+        self
+    }
+
+    pub fn coordinates(&mut self, coordinates: Coordinates) -> &mut Self {
+        self.coordinates = Some(coordinates);
+        self
+    }
+
+    pub fn phone(&mut self, phone: String) -> &mut Self {
+        self.phone = Some(phone);
+        self
+    }
+
+    pub fn zip_code(&mut self, zip_code: String) -> &mut Self {
+        self.zip_code = Some(zip_code);
+        self
+    }
+
+    pub fn yelp_business_id(&mut self, yelp_business_id: String) -> &mut Self {
+        self.yelp_business_id = Some(yelp_business_id);
+        self
+    }
+
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn match_threshold(&mut self, match_threshold: MatchThreshold) -> &mut Self {
+        self.match_threshold = Some(match_threshold);
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns `BusinessMatchPayloadError::MissingRequiredField` if any of `name`, `address1`,
+    /// `city`, `state` or `country` are not set.
+    ///
+    /// Returns `BusinessMatchPayloadError::FieldTooLong` if any field is longer than Yelp
+    /// accepts.
+    ///
+    /// Returns `BusinessMatchPayloadError::LimitOutOfRange` if `limit` is not between `1` and
+    /// `10`.
+    pub fn build(&self) -> Result<BusinessMatchPayload, BusinessMatchPayloadError> {
+        BusinessMatchPayload::new(
+            required("name", self.name.as_ref())?,
+            required("address1", self.address1.as_ref())?,
+            self.address2.clone(),
+            self.address3.clone(),
+            required("city", self.city.as_ref())?,
+            required("state", self.state.as_ref())?,
+            required("country", self.country.as_ref())?,
+            self.coordinates,
+            self.phone.clone(),
+            self.zip_code.clone(),
+            self.yelp_business_id.clone(),
+            self.limit,
+            self.match_threshold,
+        )
+    }
+}
+
+fn required(
+    field: &'static str,
+    value: Option<&String>,
+) -> Result<String, BusinessMatchPayloadError> {
+    value
+        .cloned()
+        .ok_or(BusinessMatchPayloadError::MissingRequiredField(field))
+}
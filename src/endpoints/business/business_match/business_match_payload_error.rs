@@ -0,0 +1,33 @@
+use std::error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum BusinessMatchPayloadError {
+    /// Returned when one of `name`, `address1`, `city`, `state` or `country` is not set.
+    MissingRequiredField(&'static str),
+
+    /// Returned when a field is longer than the maximum number of characters Yelp accepts for it.
+    FieldTooLong {
+        field: &'static str,
+        max_length: usize,
+    },
+
+    /// Returned when `limit` is not between `1` and `10`.
+    LimitOutOfRange(usize),
+}
+
+impl error::Error for BusinessMatchPayloadError {}
+
+impl Display for BusinessMatchPayloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingRequiredField(field) => write!(f, "{field} is required"),
+            Self::FieldTooLong { field, max_length } => {
+                write!(f, "{field} must not be longer than {max_length} characters")
+            }
+            Self::LimitOutOfRange(limit) => {
+                write!(f, "limit must be between 1 and 10: {limit}")
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::models::BusinessMatch;
+use serde::{Deserialize, Serialize};
+
+/// Response body from Business Match request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessMatchResponse {
+    /// List of businesses that best match the provided criteria.
+    pub businesses: Vec<BusinessMatch>,
+}
@@ -0,0 +1,72 @@
+mod business_match_payload;
+mod business_match_payload_builder;
+mod business_match_payload_error;
+mod business_match_response;
+
+use crate::error::Error;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use business_match_payload::*;
+pub use business_match_payload_builder::*;
+pub use business_match_payload_error::*;
+pub use business_match_response::*;
+
+impl YelpFusionClient {
+    /// Find the Yelp businesses that best match the provided name and address.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `BusinessMatchResponse`.
+    pub async fn business_match(
+        &self,
+        payload: &BusinessMatchPayload,
+    ) -> Result<BusinessMatchResponse, Error> {
+        self.get("/businesses/matches", &payload.to_query_params())
+            .await
+    }
+
+    /// Same as `business_match`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `business_match`.
+    pub async fn business_match_with_meta(
+        &self,
+        payload: &BusinessMatchPayload,
+    ) -> Result<(BusinessMatchResponse, ResponseMeta), Error> {
+        self.get_with_meta("/businesses/matches", &payload.to_query_params())
+            .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Find the Yelp businesses that best match the provided name and address.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `BusinessMatchResponse`.
+    pub fn business_match(
+        &self,
+        payload: &BusinessMatchPayload,
+    ) -> Result<BusinessMatchResponse, Error> {
+        self.get("/businesses/matches", &payload.to_query_params())
+    }
+
+    /// Same as `business_match`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `business_match`.
+    pub fn business_match_with_meta(
+        &self,
+        payload: &BusinessMatchPayload,
+    ) -> Result<(BusinessMatchResponse, ResponseMeta), Error> {
+        self.get_with_meta("/businesses/matches", &payload.to_query_params())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::models::{
+    Category, Coordinates, Hours, Location, Messaging, PriceType, SpecialHours, TransactionType,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Response body from Business Details request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessDetailsResponse {
+    /// Unique Yelp ID of this business.
+    ///
+    /// Example: '`4kMBvIEWPxWkWKFN__8SxQ`'
+    pub id: String,
+
+    /// Unique Yelp alias of this business.
+    ///
+    /// Can contain unicode characters.
+    /// Example: 'yelp-san-francisco'.
+    pub alias: String,
+
+    /// Name of this business.
+    pub name: String,
+
+    /// URL of photo for this business.
+    pub image_url: String,
+
+    /// Whether business has been claimed by a business owner.
+    pub is_claimed: bool,
+
+    /// Whether business has been (permanently) closed.
+    pub is_closed: bool,
+
+    /// URL for business page on Yelp.
+    pub url: String,
+
+    /// Phone number of the business.
+    pub phone: String,
+
+    /// Phone number of the business formatted nicely to be displayed to users.
+    /// The format is the standard phone number format for the business's country.
+    pub display_phone: String,
+
+    /// Number of reviews for this business.
+    pub review_count: usize,
+
+    /// List of category title and alias pairs associated with this business.
+    pub categories: Vec<Category>,
+
+    /// Rating for this business (value ranges from 1, 1.5, ... 4.5, 5).
+    pub rating: f32,
+
+    /// Location of this business, including address, city, state, zip code and country.
+    pub location: Location,
+
+    /// Coordinates of this business.
+    pub coordinates: Coordinates,
+
+    /// URLs of up to three photos of the business.
+    #[serde(default)]
+    pub photos: Vec<String>,
+
+    /// Price level of the business.
+    ///
+    /// Value is one of $, $$, $$$ and $$$$.
+    pub price: Option<PriceType>,
+
+    /// Opening hours of the business.
+    #[serde(default)]
+    pub hours: Vec<Hours>,
+
+    /// List of Yelp transactions that the business is registered for.
+    ///
+    /// Current supported values are pickup, delivery and `restaurant_reservation`.
+    #[serde(default)]
+    pub transactions: Vec<TransactionType>,
+
+    /// Out of the ordinary hours for the business that apply on certain dates.
+    pub special_hours: Option<Vec<SpecialHours>>,
+
+    /// Contains Business Messaging / Request a Quote information for this business.
+    ///
+    /// This field only appears in the response for businesses that have messaging enabled.
+    pub messaging: Option<Messaging>,
+
+    /// Various features or facilities provided by the business.
+    ///
+    /// The set of attributes returned is not fixed, so they are kept as raw JSON values keyed by
+    /// the attribute name.
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+}
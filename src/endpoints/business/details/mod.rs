@@ -0,0 +1,95 @@
+mod business_details_response;
+
+use crate::endpoints::encode_path_segment;
+use crate::error::Error;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use business_details_response::*;
+
+impl YelpFusionClient {
+    /// Get rich business data, such as name, address, phone number, photos, Yelp rating, price
+    /// levels and hours of operation.
+    ///
+    /// `id_or_alias` is either the Yelp ID or the Yelp alias of the business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `BusinessDetailsResponse`.
+    pub async fn business_details(
+        &self,
+        id_or_alias: &str,
+        locale: Option<String>,
+    ) -> Result<BusinessDetailsResponse, Error> {
+        self.get(
+            &format!("/businesses/{}", encode_path_segment(id_or_alias)),
+            &to_query_params(locale),
+        )
+        .await
+    }
+
+    /// Same as `business_details`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `business_details`.
+    pub async fn business_details_with_meta(
+        &self,
+        id_or_alias: &str,
+        locale: Option<String>,
+    ) -> Result<(BusinessDetailsResponse, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/businesses/{}", encode_path_segment(id_or_alias)),
+            &to_query_params(locale),
+        )
+        .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Get rich business data, such as name, address, phone number, photos, Yelp rating, price
+    /// levels and hours of operation.
+    ///
+    /// `id_or_alias` is either the Yelp ID or the Yelp alias of the business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `BusinessDetailsResponse`.
+    pub fn business_details(
+        &self,
+        id_or_alias: &str,
+        locale: Option<String>,
+    ) -> Result<BusinessDetailsResponse, Error> {
+        self.get(
+            &format!("/businesses/{}", encode_path_segment(id_or_alias)),
+            &to_query_params(locale),
+        )
+    }
+
+    /// Same as `business_details`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `business_details`.
+    pub fn business_details_with_meta(
+        &self,
+        id_or_alias: &str,
+        locale: Option<String>,
+    ) -> Result<(BusinessDetailsResponse, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/businesses/{}", encode_path_segment(id_or_alias)),
+            &to_query_params(locale),
+        )
+    }
+}
+
+fn to_query_params(locale: Option<String>) -> Vec<(&'static str, String)> {
+    locale
+        .map(|locale| vec![("locale", locale)])
+        .unwrap_or_default()
+}
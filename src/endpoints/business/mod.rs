@@ -1,3 +1,15 @@
+mod autocomplete;
+mod business_match;
+mod details;
+mod phone_search;
+mod reviews;
 mod search;
+mod transaction_search;
 
+pub use autocomplete::*;
+pub use business_match::*;
+pub use details::*;
+pub use phone_search::*;
+pub use reviews::*;
 pub use search::*;
+pub use transaction_search::*;
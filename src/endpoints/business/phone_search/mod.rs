@@ -0,0 +1,67 @@
+mod phone_search_payload;
+mod phone_search_payload_error;
+mod phone_search_response;
+
+use crate::error::Error;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use phone_search_payload::*;
+pub use phone_search_payload_error::*;
+pub use phone_search_response::*;
+
+impl YelpFusionClient {
+    /// Search for businesses by phone number.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `PhoneSearchResponse`.
+    pub async fn phone_search(
+        &self,
+        payload: &PhoneSearchPayload,
+    ) -> Result<PhoneSearchResponse, Error> {
+        self.get("/businesses/search/phone", &payload.to_query_params())
+            .await
+    }
+
+    /// Same as `phone_search`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `phone_search`.
+    pub async fn phone_search_with_meta(
+        &self,
+        payload: &PhoneSearchPayload,
+    ) -> Result<(PhoneSearchResponse, ResponseMeta), Error> {
+        self.get_with_meta("/businesses/search/phone", &payload.to_query_params())
+            .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Search for businesses by phone number.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `PhoneSearchResponse`.
+    pub fn phone_search(&self, payload: &PhoneSearchPayload) -> Result<PhoneSearchResponse, Error> {
+        self.get("/businesses/search/phone", &payload.to_query_params())
+    }
+
+    /// Same as `phone_search`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `phone_search`.
+    pub fn phone_search_with_meta(
+        &self,
+        payload: &PhoneSearchPayload,
+    ) -> Result<(PhoneSearchResponse, ResponseMeta), Error> {
+        self.get_with_meta("/businesses/search/phone", &payload.to_query_params())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::endpoints::PhoneSearchPayloadError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhoneSearchPayload {
+    /// Phone number of the business you want to search for.
+    ///
+    /// It must start with `+` and include the country code, like "+14159083801".
+    pub phone: String,
+
+    /// Specify the locale into which to localize the business information.
+    ///
+    /// See the list of [supported locales](https://www.yelp.com/developers/documentation/v3/supported_locales).
+    /// Defaults to `"en_US"`.
+    pub locale: Option<String>,
+}
+
+impl PhoneSearchPayload {
+    /// # Errors
+    ///
+    /// Returns `PhoneSearchPayloadError::MissingPlusPrefix` if `phone` does not start with `+`.
+    ///
+    /// Returns `PhoneSearchPayloadError::InvalidPhoneNumber` if `phone` is not `+` followed by
+    /// `1` to `15` digits, the first of which is not `0`.
+    pub fn new(phone: String, locale: Option<String>) -> Result<Self, PhoneSearchPayloadError> {
+        // phone must start with `+` followed by the country code
+        let Some(digits) = phone.strip_prefix('+') else {
+            return Err(PhoneSearchPayloadError::MissingPlusPrefix(phone));
+        };
+
+        // E.164 allows at most `15` digits, and country codes never start with `0`
+        if digits.is_empty()
+            || digits.len() > 15
+            || digits.starts_with('0')
+            || !digits.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(PhoneSearchPayloadError::InvalidPhoneNumber(phone));
+        }
+
+        Ok(Self { phone, locale })
+    }
+
+    #[must_use]
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut query_params: Vec<(&str, String)> = vec![];
+
+        // phone
+        query_params.push(("phone", self.phone.clone()));
+
+        // locale
+        if let Some(locale) = &self.locale {
+            query_params.push(("locale", locale.clone()));
+        }
+
+        query_params
+    }
+}
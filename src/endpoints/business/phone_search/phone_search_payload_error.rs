@@ -0,0 +1,30 @@
+use std::error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum PhoneSearchPayloadError {
+    /// Returned when `phone` does not start with `+`.
+    MissingPlusPrefix(String),
+
+    /// Returned when `phone` is not a valid E.164 phone number (`+` followed by up to `15` digits,
+    /// the first of which is not `0`).
+    InvalidPhoneNumber(String),
+}
+
+impl error::Error for PhoneSearchPayloadError {}
+
+impl Display for PhoneSearchPayloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingPlusPrefix(phone) => {
+                write!(
+                    f,
+                    "phone must start with '+' and include the country code: {phone}"
+                )
+            }
+            Self::InvalidPhoneNumber(phone) => {
+                write!(f, "phone must be a valid E.164 phone number: {phone}")
+            }
+        }
+    }
+}
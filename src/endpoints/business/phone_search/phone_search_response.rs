@@ -0,0 +1,12 @@
+use crate::models::Business;
+use serde::{Deserialize, Serialize};
+
+/// Response body from Phone Search request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhoneSearchResponse {
+    /// Total number of business Yelp finds based on the search criteria.
+    pub total: usize,
+
+    /// List of businesses Yelp finds based on the search criteria.
+    pub businesses: Vec<Business>,
+}
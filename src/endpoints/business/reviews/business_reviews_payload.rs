@@ -0,0 +1,83 @@
+use crate::endpoints::{BusinessReviewsPayloadBuilder, BusinessReviewsPayloadError};
+use crate::models::ReviewSortBy;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BusinessReviewsPayload {
+    /// Specify the locale into which to localize the reviews.
+    ///
+    /// See the list of [supported locales](https://www.yelp.com/developers/documentation/v3/supported_locales).
+    /// Defaults to `"en_US"`.
+    pub locale: Option<String>,
+
+    /// Offset the list of returned reviews by this amount.
+    pub offset: Option<usize>,
+
+    /// Number of reviews to return.
+    ///
+    /// Maximum is 50.
+    pub limit: Option<usize>,
+
+    /// Sort mode for the returned reviews.
+    ///
+    /// The default is `yelp_sort`.
+    pub sort_by: Option<ReviewSortBy>,
+}
+
+impl BusinessReviewsPayload {
+    /// # Errors
+    ///
+    /// Returns `BusinessReviewsPayloadError::LimitTooLarge` if `limit` is over `50`.
+    pub fn new(
+        locale: Option<String>,
+        offset: Option<usize>,
+        limit: Option<usize>,
+        sort_by: Option<ReviewSortBy>,
+    ) -> Result<Self, BusinessReviewsPayloadError> {
+        // make sure limit is under `50` entries
+        if let Some(limit) = limit {
+            if limit > 50 {
+                return Err(BusinessReviewsPayloadError::LimitTooLarge(limit));
+            }
+        }
+
+        Ok(Self {
+            locale,
+            offset,
+            limit,
+            sort_by,
+        })
+    }
+
+    #[must_use]
+    pub fn builder() -> BusinessReviewsPayloadBuilder {
+        BusinessReviewsPayloadBuilder::default()
+    }
+
+    #[must_use]
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut query_params: Vec<(&str, String)> = vec![];
+
+        // locale
+        if let Some(locale) = &self.locale {
+            query_params.push(("locale", locale.clone()));
+        }
+
+        // offset
+        if let Some(offset) = &self.offset {
+            query_params.push(("offset", offset.to_string()));
+        }
+
+        // limit
+        if let Some(limit) = &self.limit {
+            query_params.push(("limit", limit.to_string()));
+        }
+
+        // sort_by
+        if let Some(sort_by) = &self.sort_by {
+            query_params.push(("sort_by", sort_by.to_string()));
+        }
+
+        query_params
+    }
+}
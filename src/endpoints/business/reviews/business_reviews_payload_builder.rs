@@ -0,0 +1,53 @@
+use crate::endpoints::{BusinessReviewsPayload, BusinessReviewsPayloadError};
+use crate::models::ReviewSortBy;
+
+#[derive(Debug, Clone, Default)]
+pub struct BusinessReviewsPayloadBuilder {
+    /// Specify the locale into which to localize the reviews.
+    ///
+    /// See the list of [supported locales](https://www.yelp.com/developers/documentation/v3/supported_locales).
+    /// Defaults to `"en_US"`.
+    pub locale: Option<String>,
+
+    /// Offset the list of returned reviews by this amount.
+    pub offset: Option<usize>,
+
+    /// Number of reviews to return.
+    ///
+    /// Maximum is 50.
+    pub limit: Option<usize>,
+
+    /// Sort mode for the returned reviews.
+    ///
+    /// The default is `yelp_sort`.
+    pub sort_by: Option<ReviewSortBy>,
+}
+
+impl BusinessReviewsPayloadBuilder {
+    pub fn locale(&mut self, locale: String) -> &mut Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    pub fn offset(&mut self, offset: usize) -> &mut Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn sort_by(&mut self, sort_by: ReviewSortBy) -> &mut Self {
+        self.sort_by = Some(sort_by);
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns `BusinessReviewsPayloadError::LimitTooLarge` if `limit` is over `50`.
+    pub fn build(&self) -> Result<BusinessReviewsPayload, BusinessReviewsPayloadError> {
+        BusinessReviewsPayload::new(self.locale.clone(), self.offset, self.limit, self.sort_by)
+    }
+}
@@ -0,0 +1,18 @@
+use std::error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum BusinessReviewsPayloadError {
+    /// Returned when `limit` is set over `50`.
+    LimitTooLarge(usize),
+}
+
+impl error::Error for BusinessReviewsPayloadError {}
+
+impl Display for BusinessReviewsPayloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LimitTooLarge(limit) => write!(f, "limit must not be over 50: {limit}"),
+        }
+    }
+}
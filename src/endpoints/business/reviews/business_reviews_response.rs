@@ -0,0 +1,16 @@
+use crate::models::Review;
+use serde::{Deserialize, Serialize};
+
+/// Response body from Business Reviews request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessReviewsResponse {
+    /// The total number of reviews that the business has.
+    pub total: usize,
+
+    /// A list of up to three reviews of this business.
+    pub reviews: Vec<Review>,
+
+    /// A list of languages for which the business has at least one review.
+    #[serde(default)]
+    pub possible_languages: Vec<String>,
+}
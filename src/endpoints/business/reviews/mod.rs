@@ -0,0 +1,93 @@
+mod business_reviews_payload;
+mod business_reviews_payload_builder;
+mod business_reviews_payload_error;
+mod business_reviews_response;
+
+use crate::endpoints::encode_path_segment;
+use crate::error::Error;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use business_reviews_payload::*;
+pub use business_reviews_payload_builder::*;
+pub use business_reviews_payload_error::*;
+pub use business_reviews_response::*;
+
+impl YelpFusionClient {
+    /// Get up to three review excerpts for a business.
+    ///
+    /// `id_or_alias` is either the Yelp ID or the Yelp alias of the business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `BusinessReviewsResponse`.
+    pub async fn business_reviews(
+        &self,
+        id_or_alias: &str,
+        payload: &BusinessReviewsPayload,
+    ) -> Result<BusinessReviewsResponse, Error> {
+        self.get(
+            &format!("/businesses/{}/reviews", encode_path_segment(id_or_alias)),
+            &payload.to_query_params(),
+        )
+        .await
+    }
+
+    /// Same as `business_reviews`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `business_reviews`.
+    pub async fn business_reviews_with_meta(
+        &self,
+        id_or_alias: &str,
+        payload: &BusinessReviewsPayload,
+    ) -> Result<(BusinessReviewsResponse, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/businesses/{}/reviews", encode_path_segment(id_or_alias)),
+            &payload.to_query_params(),
+        )
+        .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Get up to three review excerpts for a business.
+    ///
+    /// `id_or_alias` is either the Yelp ID or the Yelp alias of the business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `BusinessReviewsResponse`.
+    pub fn business_reviews(
+        &self,
+        id_or_alias: &str,
+        payload: &BusinessReviewsPayload,
+    ) -> Result<BusinessReviewsResponse, Error> {
+        self.get(
+            &format!("/businesses/{}/reviews", encode_path_segment(id_or_alias)),
+            &payload.to_query_params(),
+        )
+    }
+
+    /// Same as `business_reviews`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `business_reviews`.
+    pub fn business_reviews_with_meta(
+        &self,
+        id_or_alias: &str,
+        payload: &BusinessReviewsPayload,
+    ) -> Result<(BusinessReviewsResponse, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/businesses/{}/reviews", encode_path_segment(id_or_alias)),
+            &payload.to_query_params(),
+        )
+    }
+}
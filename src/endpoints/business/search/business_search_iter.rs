@@ -0,0 +1,43 @@
+use crate::endpoints::business::search::business_search_pager::BusinessSearchPager;
+use crate::error::Error;
+use crate::models::Business;
+use crate::yelp_fusion::BlockingYelpFusionClient;
+
+/// Iterator over every business of a Business Search, requesting pages as needed.
+///
+/// Returned by `BlockingYelpFusionClient::business_search_iter`.
+#[derive(Debug)]
+pub struct BusinessSearchIter<'a> {
+    client: &'a BlockingYelpFusionClient,
+    pager: BusinessSearchPager,
+}
+
+impl<'a> BusinessSearchIter<'a> {
+    pub(crate) const fn new(
+        client: &'a BlockingYelpFusionClient,
+        pager: BusinessSearchPager,
+    ) -> Self {
+        Self { client, pager }
+    }
+}
+
+impl Iterator for BusinessSearchIter<'_> {
+    type Item = Result<Business, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(business) = self.pager.pop() {
+                return Some(Ok(business));
+            }
+
+            let payload = self.pager.next_payload()?;
+            match self.client.business_search(&payload) {
+                Ok(response) => self.pager.push_page(response),
+                Err(e) => {
+                    self.pager.stop();
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::endpoints::{BusinessSearchPayload, BusinessSearchResponse};
+use crate::models::Business;
+use std::collections::{HashSet, VecDeque};
+
+/// Number of businesses requested per page; the maximum `limit` allowed by Yelp.
+pub const BUSINESS_SEARCH_PAGE_SIZE: usize = 50;
+
+/// Maximum `limit + offset` allowed by Yelp, i.e. the most businesses a single search can page
+/// through.
+pub const BUSINESS_SEARCH_MAX_RESULTS: usize = 1000;
+
+/// Walks the pages of a Business Search, shared by the async stream and the blocking iterator.
+#[derive(Debug, Clone)]
+pub(crate) struct BusinessSearchPager {
+    payload: BusinessSearchPayload,
+
+    /// Offset of the next page to request.
+    offset: usize,
+
+    /// Number of businesses the search can page through, known after the first page.
+    total: Option<usize>,
+
+    /// Businesses of the latest page that haven't been returned yet.
+    buffer: VecDeque<Business>,
+
+    /// IDs of every business returned so far, since pages can overlap.
+    seen: HashSet<String>,
+
+    done: bool,
+}
+
+impl BusinessSearchPager {
+    pub(crate) fn new(payload: &BusinessSearchPayload) -> Self {
+        Self {
+            offset: payload.offset.unwrap_or(0),
+            payload: payload.clone(),
+            total: None,
+            buffer: VecDeque::new(),
+            seen: HashSet::new(),
+            done: false,
+        }
+    }
+
+    /// Returns the next buffered business that hasn't been returned yet.
+    pub(crate) fn pop(&mut self) -> Option<Business> {
+        while let Some(business) = self.buffer.pop_front() {
+            if self.seen.insert(business.id.clone()) {
+                return Some(business);
+            }
+        }
+        None
+    }
+
+    /// Returns the payload of the next page, or `None` if every page has been requested.
+    pub(crate) fn next_payload(&self) -> Option<BusinessSearchPayload> {
+        let end: usize = self.total.map_or(BUSINESS_SEARCH_MAX_RESULTS, |total| {
+            total.min(BUSINESS_SEARCH_MAX_RESULTS)
+        });
+        if self.done || self.offset >= end {
+            return None;
+        }
+
+        let mut payload: BusinessSearchPayload = self.payload.clone();
+        payload.limit =
+            Some(BUSINESS_SEARCH_PAGE_SIZE.min(BUSINESS_SEARCH_MAX_RESULTS - self.offset));
+        payload.offset = Some(self.offset);
+        Some(payload)
+    }
+
+    /// Buffers the businesses of the page requested with `next_payload`.
+    pub(crate) fn push_page(&mut self, response: BusinessSearchResponse) {
+        // an empty page means Yelp has nothing more to return, whatever `total` says
+        if response.businesses.is_empty() {
+            self.done = true;
+        }
+
+        self.total = Some(response.total);
+        self.offset += BUSINESS_SEARCH_PAGE_SIZE;
+        self.buffer.extend(response.businesses);
+    }
+
+    /// Stops requesting pages, e.g. after an error.
+    pub(crate) const fn stop(&mut self) {
+        self.done = true;
+    }
+}
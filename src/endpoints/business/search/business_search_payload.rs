@@ -1,7 +1,10 @@
+use crate::cache::{ResponseCache, stable_hash};
+use crate::endpoints::business::search::BUSINESS_SEARCH_PATH;
 use crate::endpoints::{BusinessSearchPayloadBuilder, BusinessSearchPayloadError};
 use crate::models::{Attribute, Coordinates, PriceType, SortBy};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use url::{ParseError, Url};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BusinessSearchPayload {
@@ -171,6 +174,8 @@ impl BusinessSearchPayload {
         BusinessSearchPayloadBuilder::default()
     }
 
+    /// Query params of the search. The output is deterministic: `price` and `attributes` are
+    /// sorted, whatever the iteration order of their sets.
     #[must_use]
     pub fn to_query_params(&self) -> Vec<(&str, String)> {
         let mut query_params: Vec<(&str, String)> = vec![];
@@ -223,9 +228,11 @@ impl BusinessSearchPayload {
 
         // price
         if let Some(price) = &self.price {
-            let comma_delimited_prices = price
+            let mut prices: Vec<usize> = price.iter().map(PriceType::as_usize).collect();
+            prices.sort_unstable();
+            let comma_delimited_prices = prices
                 .iter()
-                .map(|price| price.as_usize().to_string())
+                .map(ToString::to_string)
                 .collect::<Vec<String>>()
                 .join(",");
             query_params.push(("price", comma_delimited_prices));
@@ -243,14 +250,41 @@ impl BusinessSearchPayload {
 
         // attributes
         if let Some(attributes) = &self.attributes {
-            let comma_delimited_attributes = attributes
-                .iter()
-                .map(std::string::ToString::to_string)
-                .collect::<Vec<String>>()
-                .join(",");
+            let mut attributes: Vec<&str> = attributes.iter().map(Attribute::as_str).collect();
+            attributes.sort_unstable();
+            let comma_delimited_attributes = attributes.join(",");
             query_params.push(("attributes", comma_delimited_attributes));
         }
 
         query_params
     }
+
+    /// Canonical key of the search, as used by `ResponseCache` after the client's base URL.
+    ///
+    /// Equal searches always have the same key, across runs and platforms.
+    #[must_use]
+    pub fn cache_key(&self) -> String {
+        ResponseCache::path_key(BUSINESS_SEARCH_PATH, &self.to_query_params())
+    }
+
+    /// Hash of `cache_key`, stable across runs, platforms and Rust versions.
+    #[must_use]
+    pub fn stable_hash(&self) -> u64 {
+        stable_hash(&self.cache_key())
+    }
+
+    /// URL of the Business Search request the client sends for this payload, e.g. with
+    /// `BASE_URL` as `base_url`.
+    ///
+    /// The client's default locale, if any, isn't included.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `base_url` isn't a valid URL.
+    pub fn to_url(&self, base_url: &str) -> Result<Url, ParseError> {
+        Url::parse_with_params(
+            &format!("{}{BUSINESS_SEARCH_PATH}", base_url.trim_end_matches('/')),
+            &self.to_query_params(),
+        )
+    }
 }
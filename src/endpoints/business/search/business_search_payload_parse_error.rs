@@ -0,0 +1,52 @@
+use crate::endpoints::BusinessSearchPayloadError;
+use std::error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum BusinessSearchPayloadParseError {
+    /// Returned when a query param's key isn't a Business Search param.
+    UnknownQueryParam(String),
+
+    /// Returned when a query param's value is invalid for its key.
+    InvalidQueryParam { key: String, value: String },
+
+    /// Returned when only one of `latitude` and `longitude` is set.
+    IncompleteCoordinates,
+
+    /// Returned when the URL isn't valid.
+    InvalidUrl(String),
+
+    /// Returned when the parsed params don't make a valid `BusinessSearchPayload`.
+    InvalidPayload(BusinessSearchPayloadError),
+}
+
+impl error::Error for BusinessSearchPayloadParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidPayload(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Display for BusinessSearchPayloadParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownQueryParam(key) => write!(f, "unknown query param: {key}"),
+            Self::InvalidQueryParam { key, value } => {
+                write!(f, "invalid value for query param {key}: {value}")
+            }
+            Self::IncompleteCoordinates => {
+                write!(f, "latitude and longitude must be set together")
+            }
+            Self::InvalidUrl(url) => write!(f, "invalid URL: {url}"),
+            Self::InvalidPayload(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl From<BusinessSearchPayloadError> for BusinessSearchPayloadParseError {
+    fn from(e: BusinessSearchPayloadError) -> Self {
+        Self::InvalidPayload(e)
+    }
+}
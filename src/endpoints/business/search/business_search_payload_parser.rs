@@ -0,0 +1,262 @@
+use crate::endpoints::{
+    BusinessSearchPayload, BusinessSearchPayloadBuilder, BusinessSearchPayloadParseError,
+};
+use crate::models::{Attribute, Coordinates, PriceType, SortBy};
+use std::collections::HashSet;
+use std::str::FromStr;
+use url::{Url, form_urlencoded};
+
+/// Yelp website `attrs` mapping to a Business Search `Attribute`.
+const YELP_ATTRIBUTES: [(&str, Attribute); 6] = [
+    ("NewBusiness", Attribute::HotAndNew),
+    ("RequestAQuote", Attribute::RequestAQuote),
+    ("OnlineReservations", Attribute::Reservation),
+    ("GenderNeutralRestrooms", Attribute::GenderNeutralRestrooms),
+    ("OpenToAll", Attribute::OpenToAll),
+    ("WheelchairAccessible", Attribute::WheelchairAccessible),
+];
+
+/// Prefix of the Yelp website `attrs` filtering by price, e.g. `RestaurantsPriceRange2.2`.
+const YELP_PRICE_ATTRIBUTE_PREFIX: &str = "RestaurantsPriceRange2.";
+
+impl BusinessSearchPayload {
+    /// Parses the query params of a Business Search, the inverse of `to_query_params`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BusinessSearchPayloadParseError::UnknownQueryParam` if a key isn't a Business Search
+    /// param.
+    ///
+    /// Returns `BusinessSearchPayloadParseError::InvalidQueryParam` if a value can't be parsed, or
+    /// if a key is repeated.
+    ///
+    /// Returns `BusinessSearchPayloadParseError::IncompleteCoordinates` if only one of `latitude`
+    /// and `longitude` is set.
+    ///
+    /// Returns `BusinessSearchPayloadParseError::InvalidPayload` with the error of
+    /// `BusinessSearchPayload::new` if the parsed params aren't a valid payload.
+    pub fn from_query_params<K: AsRef<str>, V: AsRef<str>, I: IntoIterator<Item = (K, V)>>(
+        query_params: I,
+    ) -> Result<Self, BusinessSearchPayloadParseError> {
+        let mut builder: BusinessSearchPayloadBuilder = Self::builder();
+        let (mut latitude, mut longitude): (Option<f32>, Option<f32>) = (None, None);
+        let mut keys: HashSet<String> = HashSet::new();
+
+        for (key, value) in query_params {
+            let (key, value): (&str, &str) = (key.as_ref(), value.as_ref());
+            // a repeated key is ambiguous, rather than overwriting the earlier value
+            if !keys.insert(key.to_string()) {
+                return Err(invalid(key, value));
+            }
+            match key {
+                "term" => {
+                    builder.term(value.to_string());
+                }
+                "location" => {
+                    builder.location(value.to_string());
+                }
+                "latitude" => latitude = Some(parse_value(key, value)?),
+                "longitude" => longitude = Some(parse_value(key, value)?),
+                "radius" => {
+                    builder.radius(parse_value(key, value)?);
+                }
+                "categories" => {
+                    builder.categories(split_list(value).map(ToString::to_string).collect());
+                }
+                "locale" => {
+                    builder.locale(value.to_string());
+                }
+                "limit" => {
+                    builder.limit(parse_value(key, value)?);
+                }
+                "offset" => {
+                    builder.offset(parse_value(key, value)?);
+                }
+                "sort_by" => {
+                    builder.sort_by(parse_enum(key, value, &SortBy::ALL, SortBy::as_str)?);
+                }
+                "price" => {
+                    builder.price(
+                        split_list(value)
+                            .map(|price| {
+                                let price: usize = parse_value(key, price)?;
+                                PriceType::ALL
+                                    .into_iter()
+                                    .find(|price_type| price_type.as_usize() == price)
+                                    .ok_or_else(|| invalid(key, value))
+                            })
+                            .collect::<Result<HashSet<PriceType>, BusinessSearchPayloadParseError>>(
+                            )?,
+                    );
+                }
+                "open_now" => {
+                    builder.open_now(parse_value(key, value)?);
+                }
+                "open_at" => {
+                    builder.open_at(parse_value(key, value)?);
+                }
+                "attributes" => {
+                    builder.attributes(
+                        split_list(value)
+                            .map(|attribute| {
+                                parse_enum(key, attribute, &Attribute::ALL, Attribute::as_str)
+                            })
+                            .collect::<Result<HashSet<Attribute>, BusinessSearchPayloadParseError>>(
+                            )?,
+                    );
+                }
+                _ => {
+                    return Err(BusinessSearchPayloadParseError::UnknownQueryParam(
+                        key.to_string(),
+                    ));
+                }
+            }
+        }
+
+        match (latitude, longitude) {
+            (Some(latitude), Some(longitude)) => {
+                builder.coordinates(Coordinates::new(latitude, longitude));
+            }
+            (None, None) => {}
+            _ => return Err(BusinessSearchPayloadParseError::IncompleteCoordinates),
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Maps what can be mapped of a yelp.com search URL, e.g.
+    /// `https://www.yelp.com/search?find_desc=Pizza&find_loc=San+Francisco`:
+    /// `find_desc` (term), `find_loc` (location), `cflt` (categories), `sortby` and the price
+    /// and attribute filters of `attrs`. Other params are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BusinessSearchPayloadParseError::InvalidUrl` if `url` isn't a valid URL, or isn't
+    /// a URL of the yelp.com website.
+    ///
+    /// Returns `BusinessSearchPayloadParseError::InvalidPayload` with the error of
+    /// `BusinessSearchPayload::new` if the parsed params aren't a valid payload.
+    pub fn from_yelp_url(url: &str) -> Result<Self, BusinessSearchPayloadParseError> {
+        let url: Url = Url::parse(url)
+            .map_err(|_| BusinessSearchPayloadParseError::InvalidUrl(url.to_string()))?;
+        if !is_yelp_website(&url) {
+            return Err(BusinessSearchPayloadParseError::InvalidUrl(url.to_string()));
+        }
+
+        let mut builder: BusinessSearchPayloadBuilder = Self::builder();
+        let mut price: HashSet<PriceType> = HashSet::new();
+        let mut attributes: HashSet<Attribute> = HashSet::new();
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "find_desc" => {
+                    builder.term(value.to_string());
+                }
+                "find_loc" => {
+                    builder.location(value.to_string());
+                }
+                "cflt" => {
+                    builder.categories(split_list(&value).map(ToString::to_string).collect());
+                }
+                "sortby" => {
+                    let sort_by: Option<SortBy> = match value.as_ref() {
+                        "recommended" => Some(SortBy::BestMatch),
+                        "rating" => Some(SortBy::Rating),
+                        "review_count" => Some(SortBy::ReviewCount),
+                        "distance" => Some(SortBy::Distance),
+                        _ => None,
+                    };
+                    if let Some(sort_by) = sort_by {
+                        builder.sort_by(sort_by);
+                    }
+                }
+                "attrs" => {
+                    for attribute in split_list(&value) {
+                        if let Some(price_type) = attribute
+                            .strip_prefix(YELP_PRICE_ATTRIBUTE_PREFIX)
+                            .and_then(|price| price.parse::<usize>().ok())
+                            .and_then(|price| {
+                                PriceType::ALL
+                                    .into_iter()
+                                    .find(|price_type| price_type.as_usize() == price)
+                            })
+                        {
+                            price.insert(price_type);
+                        } else if let Some((_, attribute)) =
+                            YELP_ATTRIBUTES.iter().find(|(name, _)| *name == attribute)
+                        {
+                            attributes.insert(*attribute);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if !price.is_empty() {
+            builder.price(price);
+        }
+        if !attributes.is_empty() {
+            builder.attributes(attributes);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+impl FromStr for BusinessSearchPayload {
+    type Err = BusinessSearchPayloadParseError;
+
+    /// Parses a query string (with or without a leading `?`), a Business Search API URL, or a
+    /// yelp.com search URL.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: &str = s.trim();
+        if !s.starts_with("http://") && !s.starts_with("https://") {
+            let query: &str = s.strip_prefix('?').unwrap_or(s);
+            return Self::from_query_params(form_urlencoded::parse(query.as_bytes()));
+        }
+
+        let url: Url = Url::parse(s)
+            .map_err(|_| BusinessSearchPayloadParseError::InvalidUrl(s.to_string()))?;
+        if is_yelp_website(&url) {
+            return Self::from_yelp_url(s);
+        }
+        Self::from_query_params(url.query_pairs())
+    }
+}
+
+/// Whether `url` is a URL of the yelp.com website (rather than of the API).
+fn is_yelp_website(url: &Url) -> bool {
+    url.host_str().is_some_and(|host| {
+        host == "yelp.com" || (host.ends_with(".yelp.com") && host != "api.yelp.com")
+    })
+}
+
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+fn parse_value<T: FromStr>(key: &str, value: &str) -> Result<T, BusinessSearchPayloadParseError> {
+    value.parse().map_err(|_| invalid(key, value))
+}
+
+fn parse_enum<T: Copy>(
+    key: &str,
+    value: &str,
+    variants: &[T],
+    as_str: fn(&T) -> &str,
+) -> Result<T, BusinessSearchPayloadParseError> {
+    variants
+        .iter()
+        .find(|variant| as_str(variant) == value)
+        .copied()
+        .ok_or_else(|| invalid(key, value))
+}
+
+fn invalid(key: &str, value: &str) -> BusinessSearchPayloadParseError {
+    BusinessSearchPayloadParseError::InvalidQueryParam {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
@@ -0,0 +1,15 @@
+use crate::models::{BoundingBox, Business};
+
+/// Businesses found by `business_search_tiled`.
+#[derive(Debug, Clone)]
+pub struct BusinessSearchTiledResponse {
+    /// Every business found within the searched area, each of them once.
+    pub businesses: Vec<Business>,
+
+    /// Tiles that still held more than `BUSINESS_SEARCH_MAX_RESULTS` businesses once shrunk to
+    /// `BUSINESS_SEARCH_MIN_TILE_RADIUS`, so that only the first `BUSINESS_SEARCH_MAX_RESULTS`
+    /// businesses of each were found.
+    ///
+    /// Empty when `businesses` holds every business of the area.
+    pub truncated_tiles: Vec<BoundingBox>,
+}
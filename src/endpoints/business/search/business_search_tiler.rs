@@ -0,0 +1,102 @@
+use crate::endpoints::business::search::BUSINESS_SEARCH_MAX_RESULTS;
+use crate::endpoints::{BusinessSearchPayload, BusinessSearchTiledResponse};
+use crate::models::{BoundingBox, Business, SearchArea};
+use std::collections::HashSet;
+
+/// Maximum `radius` of a Business Search, in meters.
+pub const BUSINESS_SEARCH_MAX_RADIUS: usize = 40_000;
+
+/// Tiles smaller than this (in meters of radius) aren't subdivided any further, even if they
+/// hold more than `BUSINESS_SEARCH_MAX_RESULTS` businesses; such tiles are reported in
+/// `BusinessSearchTiledResponse::truncated_tiles`.
+pub const BUSINESS_SEARCH_MIN_TILE_RADIUS: usize = 250;
+
+/// Covers a `SearchArea` with circular searches, shared by the async and blocking clients.
+///
+/// Each tile is a box searched with the circle circumscribing it, so neighbouring circles
+/// overlap and together cover the whole area.
+#[derive(Debug, Clone)]
+pub(crate) struct BusinessSearchTiler {
+    payload: BusinessSearchPayload,
+    area: SearchArea,
+
+    /// Tiles that still have to be searched.
+    tiles: Vec<BoundingBox>,
+
+    /// IDs of every business found so far, since tiles overlap.
+    seen: HashSet<String>,
+
+    businesses: Vec<Business>,
+
+    /// Tiles too crowded to be paged through, but too small to be split.
+    truncated_tiles: Vec<BoundingBox>,
+}
+
+impl BusinessSearchTiler {
+    pub(crate) fn new(payload: &BusinessSearchPayload, area: &SearchArea) -> Self {
+        Self {
+            payload: payload.clone(),
+            area: *area,
+            tiles: vec![area.bounding_box()],
+            seen: HashSet::new(),
+            businesses: Vec::new(),
+            truncated_tiles: Vec::new(),
+        }
+    }
+
+    /// Returns the next tile to search along with the payload searching it, splitting tiles
+    /// that are too large for a single search.
+    pub(crate) fn next_tile(&mut self) -> Option<(BoundingBox, BusinessSearchPayload)> {
+        loop {
+            let tile: BoundingBox = self.tiles.pop()?;
+            let radius: usize = tile_radius(&tile);
+            if radius > BUSINESS_SEARCH_MAX_RADIUS {
+                self.tiles.extend(tile.quadrants());
+                continue;
+            }
+
+            let mut payload: BusinessSearchPayload = self.payload.clone();
+            payload.location = None;
+            payload.coordinates = Some(tile.center());
+            payload.radius = Some(radius);
+            payload.offset = None;
+            return Some((tile, payload));
+        }
+    }
+
+    /// Queues the quarters of `tile` instead if its search found more businesses than can be
+    /// paged through, returning whether it did.
+    ///
+    /// Crowded tiles too small to be split are recorded as truncated instead.
+    pub(crate) fn split_if_crowded(&mut self, tile: &BoundingBox, total: usize) -> bool {
+        if total <= BUSINESS_SEARCH_MAX_RESULTS {
+            return false;
+        }
+        if tile_radius(tile) <= BUSINESS_SEARCH_MIN_TILE_RADIUS {
+            self.truncated_tiles.push(*tile);
+            return false;
+        }
+
+        self.tiles.extend(tile.quadrants());
+        true
+    }
+
+    /// Keeps `business` if it lies within the searched area and hasn't been found before.
+    pub(crate) fn add(&mut self, business: Business) {
+        if self.area.contains(&business.coordinates) && self.seen.insert(business.id.clone()) {
+            self.businesses.push(business);
+        }
+    }
+
+    pub(crate) fn into_response(self) -> BusinessSearchTiledResponse {
+        BusinessSearchTiledResponse {
+            businesses: self.businesses,
+            truncated_tiles: self.truncated_tiles,
+        }
+    }
+}
+
+#[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn tile_radius(tile: &BoundingBox) -> usize {
+    tile.circumradius().ceil() as usize
+}
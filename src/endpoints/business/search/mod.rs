@@ -1,17 +1,34 @@
+mod business_search_iter;
+mod business_search_pager;
 mod business_search_payload;
 mod business_search_payload_builder;
 mod business_search_payload_error;
+mod business_search_payload_parse_error;
+mod business_search_payload_parser;
 mod business_search_response;
+mod business_search_tiled_response;
+mod business_search_tiler;
 
-use crate::error::{ApiErrorResponse, Error};
+use crate::error::Error;
+use crate::models::{Business, SearchArea};
+use crate::response_meta::ResponseMeta;
 use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use business_search_iter::*;
+use business_search_pager::BusinessSearchPager;
+pub use business_search_pager::{BUSINESS_SEARCH_MAX_RESULTS, BUSINESS_SEARCH_PAGE_SIZE};
 pub use business_search_payload::*;
 pub use business_search_payload_builder::*;
 pub use business_search_payload_error::*;
+pub use business_search_payload_parse_error::*;
 pub use business_search_response::*;
-use bytes::Bytes;
-use reqwest::blocking::RequestBuilder as BlockingRequestBuilder;
-use reqwest::{RequestBuilder, StatusCode};
+pub use business_search_tiled_response::*;
+use business_search_tiler::BusinessSearchTiler;
+pub use business_search_tiler::{BUSINESS_SEARCH_MAX_RADIUS, BUSINESS_SEARCH_MIN_TILE_RADIUS};
+use futures::stream::{self, Stream, StreamExt};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{panic, thread};
+
+pub(crate) const BUSINESS_SEARCH_PATH: &str = "/businesses/search";
 
 impl YelpFusionClient {
     /// # Errors
@@ -23,32 +40,113 @@ impl YelpFusionClient {
         &self,
         payload: &BusinessSearchPayload,
     ) -> Result<BusinessSearchResponse, Error> {
-        // create request
-        let request: RequestBuilder = self
-            .client
-            .get(format!("{}/businesses/search", self.base_url))
-            .bearer_auth(self.api_key.clone())
-            .query(&payload.to_query_params());
-
-        // send request, get response
-        let response = request.send().await?;
-
-        // parse status code and returned bytes
-        let status_code: StatusCode = response.status();
-        let bytes: Bytes = response.bytes().await?;
-
-        // check if failure
-        if !status_code.is_success() {
-            let error_response: ApiErrorResponse = serde_json::from_slice(&bytes)?;
-            return Err(Error::RequestFailed {
-                error_response,
-                status_code,
-            });
-        }
+        self.get(BUSINESS_SEARCH_PATH, &payload.to_query_params())
+            .await
+    }
+
+    /// Same as `business_search`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `business_search`.
+    pub async fn business_search_with_meta(
+        &self,
+        payload: &BusinessSearchPayload,
+    ) -> Result<(BusinessSearchResponse, ResponseMeta), Error> {
+        self.get_with_meta(BUSINESS_SEARCH_PATH, &payload.to_query_params())
+            .await
+    }
+
+    /// Streams every business of the search, requesting pages of `BUSINESS_SEARCH_PAGE_SIZE`
+    /// businesses (starting at `payload.offset`) until `total` or `BUSINESS_SEARCH_MAX_RESULTS`
+    /// is reached. `payload.limit` is ignored.
+    ///
+    /// Pages are only requested when the stream is polled, so dropping it early stops the
+    /// search. Businesses appearing on several pages are only returned once.
+    ///
+    /// The stream ends after returning the first error.
+    pub fn business_search_stream<'a>(
+        &'a self,
+        payload: &BusinessSearchPayload,
+    ) -> impl Stream<Item = Result<Business, Error>> + 'a {
+        stream::unfold(
+            BusinessSearchPager::new(payload),
+            move |mut pager| async move {
+                loop {
+                    if let Some(business) = pager.pop() {
+                        return Some((Ok(business), pager));
+                    }
+
+                    let payload: BusinessSearchPayload = pager.next_payload()?;
+                    match self.business_search(&payload).await {
+                        Ok(response) => pager.push_page(response),
+                        Err(e) => {
+                            pager.stop();
+                            return Some((Err(e), pager));
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Runs every search of `payloads`, with at most `concurrency` of them in flight at once
+    /// (at least one), returning their results in the same order as `payloads`.
+    ///
+    /// Requests still go through the client's `RateLimiter` and `RetryPolicy`, if any.
+    pub async fn search_many(
+        &self,
+        payloads: &[BusinessSearchPayload],
+        concurrency: usize,
+    ) -> Vec<Result<BusinessSearchResponse, Error>> {
+        stream::iter(payloads)
+            .map(|payload| self.business_search(payload))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Finds every business of `area` matching `payload`, getting past the
+    /// `BUSINESS_SEARCH_MAX_RESULTS` cap of a single search.
+    ///
+    /// The area is covered with overlapping circular searches no larger than
+    /// `BUSINESS_SEARCH_MAX_RADIUS`, and any search finding more businesses than can be paged
+    /// through is replaced by searches of its four quarters. The `location`, `coordinates`,
+    /// `radius`, `limit` and `offset` of `payload` are ignored.
+    ///
+    /// Only businesses within `area` are returned, each of them once. Tiles are not split below
+    /// `BUSINESS_SEARCH_MIN_TILE_RADIUS`, so a tile still holding more than
+    /// `BUSINESS_SEARCH_MAX_RESULTS` businesses at that size only yields its first
+    /// `BUSINESS_SEARCH_MAX_RESULTS` businesses, and is listed in `truncated_tiles`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the searches fails, under the same conditions as
+    /// `business_search`.
+    pub async fn business_search_tiled(
+        &self,
+        payload: &BusinessSearchPayload,
+        area: &SearchArea,
+    ) -> Result<BusinessSearchTiledResponse, Error> {
+        let mut tiler: BusinessSearchTiler = BusinessSearchTiler::new(payload, area);
+        while let Some((tile, tile_payload)) = tiler.next_tile() {
+            let mut pager: BusinessSearchPager = BusinessSearchPager::new(&tile_payload);
+            while let Some(page_payload) = pager.next_payload() {
+                let response: BusinessSearchResponse = self.business_search(&page_payload).await?;
 
-        // success
-        let response: BusinessSearchResponse = serde_json::from_slice(&bytes)?;
-        Ok(response)
+                // too many businesses for a single search, search the quarters of the tile instead
+                if page_payload.offset == Some(0) && tiler.split_if_crowded(&tile, response.total) {
+                    break;
+                }
+
+                pager.push_page(response);
+                while let Some(business) = pager.pop() {
+                    tiler.add(business);
+                }
+            }
+        }
+        Ok(tiler.into_response())
     }
 }
 
@@ -62,31 +160,110 @@ impl BlockingYelpFusionClient {
         &self,
         payload: &BusinessSearchPayload,
     ) -> Result<BusinessSearchResponse, Error> {
-        // create request
-        let request: BlockingRequestBuilder = self
-            .client
-            .get(format!("{}/businesses/search", self.base_url))
-            .bearer_auth(self.api_key.clone())
-            .query(&payload.to_query_params());
-
-        // send request, get response
-        let response = request.send()?;
-
-        // parse status code and returned bytes
-        let status_code: StatusCode = response.status();
-        let bytes: Bytes = response.bytes()?;
-
-        // check if failure
-        if !status_code.is_success() {
-            let error_response: ApiErrorResponse = serde_json::from_slice(&bytes)?;
-            return Err(Error::RequestFailed {
-                error_response,
-                status_code,
+        self.get(BUSINESS_SEARCH_PATH, &payload.to_query_params())
+    }
+
+    /// Same as `business_search`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `business_search`.
+    pub fn business_search_with_meta(
+        &self,
+        payload: &BusinessSearchPayload,
+    ) -> Result<(BusinessSearchResponse, ResponseMeta), Error> {
+        self.get_with_meta(BUSINESS_SEARCH_PATH, &payload.to_query_params())
+    }
+
+    /// Iterates over every business of the search, requesting pages of
+    /// `BUSINESS_SEARCH_PAGE_SIZE` businesses (starting at `payload.offset`) until `total` or
+    /// `BUSINESS_SEARCH_MAX_RESULTS` is reached. `payload.limit` is ignored.
+    ///
+    /// Pages are only requested as the iterator advances, so dropping it early stops the search.
+    /// Businesses appearing on several pages are only returned once.
+    ///
+    /// The iterator ends after returning the first error.
+    #[must_use]
+    pub fn business_search_iter(&self, payload: &BusinessSearchPayload) -> BusinessSearchIter<'_> {
+        BusinessSearchIter::new(self, BusinessSearchPager::new(payload))
+    }
+
+    /// Runs every search of `payloads` on at most `concurrency` threads (at least one),
+    /// returning their results in the same order as `payloads`.
+    ///
+    /// Requests still go through the client's `RateLimiter` and `RetryPolicy`, if any.
+    #[must_use]
+    pub fn search_many(
+        &self,
+        payloads: &[BusinessSearchPayload],
+        concurrency: usize,
+    ) -> Vec<Result<BusinessSearchResponse, Error>> {
+        let next: AtomicUsize = AtomicUsize::new(0);
+        let mut results: Vec<(usize, Result<BusinessSearchResponse, Error>)> =
+            thread::scope(|scope| {
+                let workers: Vec<_> = (0..concurrency.clamp(1, payloads.len().max(1)))
+                    .map(|_| {
+                        scope.spawn(|| {
+                            let mut results = Vec::new();
+                            loop {
+                                let index: usize = next.fetch_add(1, Ordering::Relaxed);
+                                let Some(payload) = payloads.get(index) else {
+                                    return results;
+                                };
+                                results.push((index, self.business_search(payload)));
+                            }
+                        })
+                    })
+                    .collect();
+                workers
+                    .into_iter()
+                    .flat_map(|worker| worker.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                    .collect()
             });
-        }
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Finds every business of `area` matching `payload`, getting past the
+    /// `BUSINESS_SEARCH_MAX_RESULTS` cap of a single search.
+    ///
+    /// The area is covered with overlapping circular searches no larger than
+    /// `BUSINESS_SEARCH_MAX_RADIUS`, and any search finding more businesses than can be paged
+    /// through is replaced by searches of its four quarters. The `location`, `coordinates`,
+    /// `radius`, `limit` and `offset` of `payload` are ignored.
+    ///
+    /// Only businesses within `area` are returned, each of them once. Tiles are not split below
+    /// `BUSINESS_SEARCH_MIN_TILE_RADIUS`, so a tile still holding more than
+    /// `BUSINESS_SEARCH_MAX_RESULTS` businesses at that size only yields its first
+    /// `BUSINESS_SEARCH_MAX_RESULTS` businesses, and is listed in `truncated_tiles`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the searches fails, under the same conditions as
+    /// `business_search`.
+    pub fn business_search_tiled(
+        &self,
+        payload: &BusinessSearchPayload,
+        area: &SearchArea,
+    ) -> Result<BusinessSearchTiledResponse, Error> {
+        let mut tiler: BusinessSearchTiler = BusinessSearchTiler::new(payload, area);
+        while let Some((tile, tile_payload)) = tiler.next_tile() {
+            let mut pager: BusinessSearchPager = BusinessSearchPager::new(&tile_payload);
+            while let Some(page_payload) = pager.next_payload() {
+                let response: BusinessSearchResponse = self.business_search(&page_payload)?;
+
+                // too many businesses for a single search, search the quarters of the tile instead
+                if page_payload.offset == Some(0) && tiler.split_if_crowded(&tile, response.total) {
+                    break;
+                }
 
-        // success
-        let response: BusinessSearchResponse = serde_json::from_slice(&bytes)?;
-        Ok(response)
+                pager.push_page(response);
+                while let Some(business) = pager.pop() {
+                    tiler.add(business);
+                }
+            }
+        }
+        Ok(tiler.into_response())
     }
 }
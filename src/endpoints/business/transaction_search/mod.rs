@@ -0,0 +1,82 @@
+mod transaction_search_payload;
+mod transaction_search_payload_error;
+mod transaction_search_response;
+
+use crate::error::Error;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use transaction_search_payload::*;
+pub use transaction_search_payload_error::*;
+pub use transaction_search_response::*;
+
+impl YelpFusionClient {
+    /// Search for businesses which support food delivery transactions.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `TransactionSearchResponse`.
+    pub async fn transaction_search(
+        &self,
+        payload: &TransactionSearchPayload,
+    ) -> Result<TransactionSearchResponse, Error> {
+        self.get(
+            &format!("/transactions/{}/search", payload.transaction_type),
+            &payload.to_query_params(),
+        )
+        .await
+    }
+
+    /// Same as `transaction_search`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `transaction_search`.
+    pub async fn transaction_search_with_meta(
+        &self,
+        payload: &TransactionSearchPayload,
+    ) -> Result<(TransactionSearchResponse, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/transactions/{}/search", payload.transaction_type),
+            &payload.to_query_params(),
+        )
+        .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Search for businesses which support food delivery transactions.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `TransactionSearchResponse`.
+    pub fn transaction_search(
+        &self,
+        payload: &TransactionSearchPayload,
+    ) -> Result<TransactionSearchResponse, Error> {
+        self.get(
+            &format!("/transactions/{}/search", payload.transaction_type),
+            &payload.to_query_params(),
+        )
+    }
+
+    /// Same as `transaction_search`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `transaction_search`.
+    pub fn transaction_search_with_meta(
+        &self,
+        payload: &TransactionSearchPayload,
+    ) -> Result<(TransactionSearchResponse, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/transactions/{}/search", payload.transaction_type),
+            &payload.to_query_params(),
+        )
+    }
+}
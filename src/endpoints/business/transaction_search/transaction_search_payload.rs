@@ -0,0 +1,79 @@
+use crate::endpoints::TransactionSearchPayloadError;
+use crate::models::{Coordinates, TransactionType};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionSearchPayload {
+    /// Valid values for `transaction_type` are: `delivery`.
+    pub transaction_type: TransactionType,
+
+    /// Required when latitude and longitude aren't provided.
+    ///
+    /// Address of the location you want to deliver to.
+    pub location: Option<String>,
+
+    /// Required when location isn't provided.
+    ///
+    /// Latitude and longitude of the location you want to deliver to.
+    pub coordinates: Option<Coordinates>,
+}
+
+impl TransactionSearchPayload {
+    /// # Errors
+    ///
+    /// Returns `TransactionSearchPayloadError::UnsupportedTransactionType` if `transaction_type`
+    /// is not `TransactionType::Delivery`.
+    ///
+    /// Returns `TransactionSearchPayloadError::BothLocationAndLatLongSet` if both `location` and
+    /// `coordinates` are set. Only one or the other can be set.
+    ///
+    /// Returns `TransactionSearchPayloadError::NeitherLocationNorLatLongSet` if neither `location`
+    /// nor `coordinates` are set. One or the other must be set.
+    pub fn new(
+        transaction_type: TransactionType,
+        location: Option<String>,
+        coordinates: Option<Coordinates>,
+    ) -> Result<Self, TransactionSearchPayloadError> {
+        // Yelp currently only supports delivery
+        if !matches!(transaction_type, TransactionType::Delivery) {
+            return Err(TransactionSearchPayloadError::UnsupportedTransactionType(
+                transaction_type,
+            ));
+        }
+
+        // only `location` OR `coordinates` can be set - not both, and not neither
+        match (&location, &coordinates) {
+            (Some(_), Some(_)) => {
+                return Err(TransactionSearchPayloadError::BothLocationAndLatLongSet);
+            }
+            (None, None) => {
+                return Err(TransactionSearchPayloadError::NeitherLocationNorLatLongSet);
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            transaction_type,
+            location,
+            coordinates,
+        })
+    }
+
+    #[must_use]
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut query_params: Vec<(&str, String)> = vec![];
+
+        // location
+        if let Some(location) = &self.location {
+            query_params.push(("location", location.clone()));
+        }
+
+        // latitude
+        if let Some(coordinates) = &self.coordinates {
+            query_params.push(("latitude", coordinates.latitude.to_string()));
+            query_params.push(("longitude", coordinates.longitude.to_string()));
+        }
+
+        query_params
+    }
+}
@@ -0,0 +1,35 @@
+use crate::models::TransactionType;
+use std::error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum TransactionSearchPayloadError {
+    /// Returned when the transaction type is not supported by Yelp's Transaction Search.
+    ///
+    /// Currently only `TransactionType::Delivery` is supported.
+    UnsupportedTransactionType(TransactionType),
+
+    /// Returned when both Location and Latitude/Longitude are set (must use either-or).
+    BothLocationAndLatLongSet,
+
+    /// Returned when neither Location nor Latitude/Longitude are set (must use either-or).
+    NeitherLocationNorLatLongSet,
+}
+
+impl error::Error for TransactionSearchPayloadError {}
+
+impl Display for TransactionSearchPayloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedTransactionType(transaction_type) => {
+                write!(f, "unsupported transaction type: {transaction_type}")
+            }
+            Self::BothLocationAndLatLongSet => {
+                write!(f, "can either set location OR latitude/longitude, NOT both")
+            }
+            Self::NeitherLocationNorLatLongSet => {
+                write!(f, "must set either location OR latitude/longitude")
+            }
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::models::Business;
+use serde::{Deserialize, Serialize};
+
+/// Response body from Transaction Search request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionSearchResponse {
+    /// Total number of business Yelp finds based on the search criteria.
+    pub total: usize,
+
+    /// List of businesses Yelp finds based on the search criteria.
+    pub businesses: Vec<Business>,
+}
@@ -0,0 +1,17 @@
+use crate::models::{Category, CategoryTree};
+use serde::{Deserialize, Serialize};
+
+/// Response body from All Categories request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllCategoriesResponse {
+    /// List of all business categories across all locales.
+    pub categories: Vec<Category>,
+}
+
+impl AllCategoriesResponse {
+    /// Builds the parent/child hierarchy of the returned categories.
+    #[must_use]
+    pub fn to_tree(&self) -> CategoryTree {
+        CategoryTree::new(self.categories.clone())
+    }
+}
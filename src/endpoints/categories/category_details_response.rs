@@ -0,0 +1,9 @@
+use crate::models::Category;
+use serde::{Deserialize, Serialize};
+
+/// Response body from Category Details request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryDetailsResponse {
+    /// The requested category.
+    pub category: Category,
+}
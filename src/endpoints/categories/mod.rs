@@ -0,0 +1,147 @@
+mod all_categories_response;
+mod category_details_response;
+
+use crate::endpoints::encode_path_segment;
+use crate::error::Error;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use all_categories_response::*;
+pub use category_details_response::*;
+
+impl YelpFusionClient {
+    /// Get all business categories across all locales by default.
+    ///
+    /// When `locale` is set, only the categories available in that locale are returned.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `AllCategoriesResponse`.
+    pub async fn all_categories(
+        &self,
+        locale: Option<String>,
+    ) -> Result<AllCategoriesResponse, Error> {
+        self.get("/categories", &to_query_params(locale)).await
+    }
+
+    /// Same as `all_categories`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `all_categories`.
+    pub async fn all_categories_with_meta(
+        &self,
+        locale: Option<String>,
+    ) -> Result<(AllCategoriesResponse, ResponseMeta), Error> {
+        self.get_with_meta("/categories", &to_query_params(locale))
+            .await
+    }
+
+    /// Get detailed information about the Yelp business category specified by `alias`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `CategoryDetailsResponse`.
+    pub async fn category_details(
+        &self,
+        alias: &str,
+        locale: Option<String>,
+    ) -> Result<CategoryDetailsResponse, Error> {
+        self.get(
+            &format!("/categories/{}", encode_path_segment(alias)),
+            &to_query_params(locale),
+        )
+        .await
+    }
+
+    /// Same as `category_details`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `category_details`.
+    pub async fn category_details_with_meta(
+        &self,
+        alias: &str,
+        locale: Option<String>,
+    ) -> Result<(CategoryDetailsResponse, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/categories/{}", encode_path_segment(alias)),
+            &to_query_params(locale),
+        )
+        .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Get all business categories across all locales by default.
+    ///
+    /// When `locale` is set, only the categories available in that locale are returned.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `AllCategoriesResponse`.
+    pub fn all_categories(&self, locale: Option<String>) -> Result<AllCategoriesResponse, Error> {
+        self.get("/categories", &to_query_params(locale))
+    }
+
+    /// Same as `all_categories`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `all_categories`.
+    pub fn all_categories_with_meta(
+        &self,
+        locale: Option<String>,
+    ) -> Result<(AllCategoriesResponse, ResponseMeta), Error> {
+        self.get_with_meta("/categories", &to_query_params(locale))
+    }
+
+    /// Get detailed information about the Yelp business category specified by `alias`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `CategoryDetailsResponse`.
+    pub fn category_details(
+        &self,
+        alias: &str,
+        locale: Option<String>,
+    ) -> Result<CategoryDetailsResponse, Error> {
+        self.get(
+            &format!("/categories/{}", encode_path_segment(alias)),
+            &to_query_params(locale),
+        )
+    }
+
+    /// Same as `category_details`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `category_details`.
+    pub fn category_details_with_meta(
+        &self,
+        alias: &str,
+        locale: Option<String>,
+    ) -> Result<(CategoryDetailsResponse, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/categories/{}", encode_path_segment(alias)),
+            &to_query_params(locale),
+        )
+    }
+}
+
+fn to_query_params(locale: Option<String>) -> Vec<(&'static str, String)> {
+    locale
+        .map(|locale| vec![("locale", locale)])
+        .unwrap_or_default()
+}
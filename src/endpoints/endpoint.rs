@@ -0,0 +1,86 @@
+use std::fmt::{Display, Formatter, Write};
+
+/// The REST endpoints of the Yelp Fusion API.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    BusinessSearch,
+    PhoneSearch,
+    TransactionSearch,
+    BusinessDetails,
+    BusinessMatch,
+    BusinessReviews,
+    Autocomplete,
+    EventLookup,
+    EventSearch,
+    FeaturedEvent,
+    AllCategories,
+    CategoryDetails,
+}
+
+impl Endpoint {
+    /// Identifies the endpoint of a request `path` (relative to the base URL), e.g.
+    /// `/businesses/{id}/reviews`.
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<Self> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match segments.as_slice() {
+            ["businesses", "search"] => Some(Self::BusinessSearch),
+            ["businesses", "search", "phone"] => Some(Self::PhoneSearch),
+            ["transactions", _, "search"] => Some(Self::TransactionSearch),
+            ["businesses", "matches"] => Some(Self::BusinessMatch),
+            ["businesses", _] => Some(Self::BusinessDetails),
+            ["businesses", _, "reviews"] => Some(Self::BusinessReviews),
+            ["autocomplete"] => Some(Self::Autocomplete),
+            ["events"] => Some(Self::EventSearch),
+            ["events", "featured"] => Some(Self::FeaturedEvent),
+            ["events", _] => Some(Self::EventLookup),
+            ["categories"] => Some(Self::AllCategories),
+            ["categories", _] => Some(Self::CategoryDetails),
+            _ => None,
+        }
+    }
+
+    /// Whether the endpoint takes a `locale` query param, and thus the client's default locale.
+    #[must_use]
+    pub const fn accepts_locale(self) -> bool {
+        !matches!(self, Self::TransactionSearch | Self::BusinessMatch)
+    }
+
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Self::BusinessSearch => "business_search",
+            Self::PhoneSearch => "phone_search",
+            Self::TransactionSearch => "transaction_search",
+            Self::BusinessDetails => "business_details",
+            Self::BusinessMatch => "business_match",
+            Self::BusinessReviews => "business_reviews",
+            Self::Autocomplete => "autocomplete",
+            Self::EventLookup => "event_lookup",
+            Self::EventSearch => "event_search",
+            Self::FeaturedEvent => "featured_event",
+            Self::AllCategories => "all_categories",
+            Self::CategoryDetails => "category_details",
+        }
+    }
+}
+
+impl Display for Endpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Percent-encodes `segment` (e.g. a business ID) so it stays a single segment of a request path,
+/// even if it contains `/`, `?` or `#`.
+pub(crate) fn encode_path_segment(segment: &str) -> String {
+    let mut encoded: String = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
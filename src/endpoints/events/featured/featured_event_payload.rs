@@ -0,0 +1,74 @@
+use crate::endpoints::FeaturedEventPayloadError;
+use crate::models::Coordinates;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeaturedEventPayload {
+    /// Specify the locale into which to localize the event information.
+    ///
+    /// See the list of [supported locales](https://www.yelp.com/developers/documentation/v3/supported_locales).
+    /// Defaults to `"en_US"`.
+    pub locale: Option<String>,
+
+    /// Required when latitude and longitude aren't provided.
+    ///
+    /// Specifies the combination of "address, neighborhood, city, state or zip, optional country"
+    /// to be used when searching for events.
+    pub location: Option<String>,
+
+    /// Required when location isn't provided.
+    ///
+    /// Latitude and longitude of the location you want to search nearby.
+    pub coordinates: Option<Coordinates>,
+}
+
+impl FeaturedEventPayload {
+    /// # Errors
+    ///
+    /// Returns `FeaturedEventPayloadError::BothLocationAndLatLongSet` if both `location` and
+    /// `coordinates` are set. Only one or the other can be set.
+    ///
+    /// Returns `FeaturedEventPayloadError::NeitherLocationNorLatLongSet` if neither `location` nor
+    /// `coordinates` are set. One or the other must be set.
+    pub fn new(
+        locale: Option<String>,
+        location: Option<String>,
+        coordinates: Option<Coordinates>,
+    ) -> Result<Self, FeaturedEventPayloadError> {
+        // only `location` OR `coordinates` can be set - not both, and not neither
+        match (&location, &coordinates) {
+            (Some(_), Some(_)) => return Err(FeaturedEventPayloadError::BothLocationAndLatLongSet),
+            (None, None) => return Err(FeaturedEventPayloadError::NeitherLocationNorLatLongSet),
+            _ => {}
+        }
+
+        Ok(Self {
+            locale,
+            location,
+            coordinates,
+        })
+    }
+
+    #[must_use]
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut query_params: Vec<(&str, String)> = vec![];
+
+        // locale
+        if let Some(locale) = &self.locale {
+            query_params.push(("locale", locale.clone()));
+        }
+
+        // location
+        if let Some(location) = &self.location {
+            query_params.push(("location", location.clone()));
+        }
+
+        // latitude
+        if let Some(coordinates) = &self.coordinates {
+            query_params.push(("latitude", coordinates.latitude.to_string()));
+            query_params.push(("longitude", coordinates.longitude.to_string()));
+        }
+
+        query_params
+    }
+}
@@ -0,0 +1,26 @@
+use std::error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum FeaturedEventPayloadError {
+    /// Returned when both Location and Latitude/Longitude are set (must use either-or).
+    BothLocationAndLatLongSet,
+
+    /// Returned when neither Location nor Latitude/Longitude are set (must use either-or).
+    NeitherLocationNorLatLongSet,
+}
+
+impl error::Error for FeaturedEventPayloadError {}
+
+impl Display for FeaturedEventPayloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BothLocationAndLatLongSet => {
+                write!(f, "can either set location OR latitude/longitude, NOT both")
+            }
+            Self::NeitherLocationNorLatLongSet => {
+                write!(f, "must set either location OR latitude/longitude")
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+mod featured_event_payload;
+mod featured_event_payload_error;
+
+use crate::error::Error;
+use crate::models::Event;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use featured_event_payload::*;
+pub use featured_event_payload_error::*;
+
+impl YelpFusionClient {
+    /// Get the featured event for a given location.
+    ///
+    /// Featured events are chosen by Yelp's community managers.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `Event`.
+    pub async fn featured_event(&self, payload: &FeaturedEventPayload) -> Result<Event, Error> {
+        self.get("/events/featured", &payload.to_query_params())
+            .await
+    }
+
+    /// Same as `featured_event`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `featured_event`.
+    pub async fn featured_event_with_meta(
+        &self,
+        payload: &FeaturedEventPayload,
+    ) -> Result<(Event, ResponseMeta), Error> {
+        self.get_with_meta("/events/featured", &payload.to_query_params())
+            .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Get the featured event for a given location.
+    ///
+    /// Featured events are chosen by Yelp's community managers.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `Event`.
+    pub fn featured_event(&self, payload: &FeaturedEventPayload) -> Result<Event, Error> {
+        self.get("/events/featured", &payload.to_query_params())
+    }
+
+    /// Same as `featured_event`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `featured_event`.
+    pub fn featured_event_with_meta(
+        &self,
+        payload: &FeaturedEventPayload,
+    ) -> Result<(Event, ResponseMeta), Error> {
+        self.get_with_meta("/events/featured", &payload.to_query_params())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::endpoints::encode_path_segment;
+use crate::error::Error;
+use crate::models::Event;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+
+impl YelpFusionClient {
+    /// Get the detailed information of a Yelp event by its id.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `Event`.
+    pub async fn event_lookup(&self, id: &str, locale: Option<String>) -> Result<Event, Error> {
+        self.get(
+            &format!("/events/{}", encode_path_segment(id)),
+            &to_query_params(locale),
+        )
+        .await
+    }
+
+    /// Same as `event_lookup`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `event_lookup`.
+    pub async fn event_lookup_with_meta(
+        &self,
+        id: &str,
+        locale: Option<String>,
+    ) -> Result<(Event, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/events/{}", encode_path_segment(id)),
+            &to_query_params(locale),
+        )
+        .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Get the detailed information of a Yelp event by its id.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `Event`.
+    pub fn event_lookup(&self, id: &str, locale: Option<String>) -> Result<Event, Error> {
+        self.get(
+            &format!("/events/{}", encode_path_segment(id)),
+            &to_query_params(locale),
+        )
+    }
+
+    /// Same as `event_lookup`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `event_lookup`.
+    pub fn event_lookup_with_meta(
+        &self,
+        id: &str,
+        locale: Option<String>,
+    ) -> Result<(Event, ResponseMeta), Error> {
+        self.get_with_meta(
+            &format!("/events/{}", encode_path_segment(id)),
+            &to_query_params(locale),
+        )
+    }
+}
+
+fn to_query_params(locale: Option<String>) -> Vec<(&'static str, String)> {
+    locale
+        .map(|locale| vec![("locale", locale)])
+        .unwrap_or_default()
+}
@@ -0,0 +1,6 @@
+mod featured;
+mod lookup;
+mod search;
+
+pub use featured::*;
+pub use search::*;
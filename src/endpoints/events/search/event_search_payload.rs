@@ -0,0 +1,225 @@
+use crate::endpoints::{EventSearchPayloadBuilder, EventSearchPayloadError};
+use crate::models::{Coordinates, EventSortBy, EventSortOn};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSearchPayload {
+    /// Specify the locale into which to localize the event information.
+    ///
+    /// See the list of [supported locales](https://www.yelp.com/developers/documentation/v3/supported_locales).
+    /// Defaults to `"en_US"`.
+    pub locale: Option<String>,
+
+    /// Offset the list of returned events by this amount.
+    pub offset: Option<usize>,
+
+    /// Number of events results to return.
+    ///
+    /// By default, it will return 3.
+    /// Maximum is 50.
+    pub limit: Option<usize>,
+
+    /// Sort by either descending or ascending order.
+    ///
+    /// By default, it returns results in descending order.
+    pub sort_by: Option<EventSortBy>,
+
+    /// Sort on popularity or time start.
+    ///
+    /// By default, sorts on popularity.
+    pub sort_on: Option<EventSortOn>,
+
+    /// Unix timestamp of the event start time.
+    ///
+    /// Will return events that only begin at or after the specified time.
+    pub start_date: Option<usize>,
+
+    /// Unix timestamp of the event end time.
+    ///
+    /// Will return events that only end at or before the specified time.
+    pub end_date: Option<usize>,
+
+    /// Categories to filter the events with.
+    ///
+    /// See the list of [supported event categories](https://www.yelp.com/developers/documentation/v3/event_categories).
+    pub categories: Option<Vec<String>>,
+
+    /// Filter whether the events are free to attend.
+    ///
+    /// By default no filter is applied, so both free and paid events will be returned.
+    pub is_free: Option<bool>,
+
+    /// Specifies the combination of "address, neighborhood, city, state or zip, optional country"
+    /// to be used when searching for events.
+    pub location: Option<String>,
+
+    /// Latitude and longitude of the location you want to search nearby.
+    ///
+    /// Cannot be used together with `location`.
+    pub coordinates: Option<Coordinates>,
+
+    /// Search radius in meters.
+    ///
+    /// If the value is too large, a `AREA_TOO_LARGE` error may be returned.
+    /// The max value is 40000 meters (25 miles).
+    pub radius: Option<usize>,
+
+    /// List of event ids.
+    ///
+    /// Events associated with these event ids in this list will not show up in the response.
+    pub excluded_events: Option<Vec<String>>,
+}
+
+impl EventSearchPayload {
+    /// # Errors
+    ///
+    /// Returns `EventSearchPayloadError::BothLocationAndLatLongSet` if both `location` and
+    /// `coordinates` are set. Only one or the other can be set.
+    ///
+    /// Returns `EventSearchPayloadError::RadiusTooLarge` if `radius` is over `40,000` meters
+    /// (~25 miles).
+    ///
+    /// Returns `EventSearchPayloadError::LimitTooLarge` if `limit` is over `50`.
+    ///
+    /// Returns `EventSearchPayloadError::EndDateBeforeStartDate` if `end_date` is before
+    /// `start_date`.
+    #[expect(clippy::too_many_arguments)]
+    pub fn new(
+        locale: Option<String>,
+        offset: Option<usize>,
+        limit: Option<usize>,
+        sort_by: Option<EventSortBy>,
+        sort_on: Option<EventSortOn>,
+        start_date: Option<usize>,
+        end_date: Option<usize>,
+        categories: Option<Vec<String>>,
+        is_free: Option<bool>,
+        location: Option<String>,
+        coordinates: Option<Coordinates>,
+        radius: Option<usize>,
+        excluded_events: Option<Vec<String>>,
+    ) -> Result<Self, EventSearchPayloadError> {
+        // only `location` OR `coordinates` can be set - not both
+        if location.is_some() && coordinates.is_some() {
+            return Err(EventSearchPayloadError::BothLocationAndLatLongSet);
+        }
+
+        // make sure radius is under `40,000` meters (~25 miles)
+        if let Some(radius) = radius {
+            if radius > 40_000 {
+                return Err(EventSearchPayloadError::RadiusTooLarge(radius));
+            }
+        }
+
+        // make sure limit is under `50` entries
+        if let Some(limit) = limit {
+            if limit > 50 {
+                return Err(EventSearchPayloadError::LimitTooLarge(limit));
+            }
+        }
+
+        // make sure the date range isn't inverted
+        if let (Some(start_date), Some(end_date)) = (start_date, end_date) {
+            if end_date < start_date {
+                return Err(EventSearchPayloadError::EndDateBeforeStartDate {
+                    start_date,
+                    end_date,
+                });
+            }
+        }
+
+        Ok(Self {
+            locale,
+            offset,
+            limit,
+            sort_by,
+            sort_on,
+            start_date,
+            end_date,
+            categories,
+            is_free,
+            location,
+            coordinates,
+            radius,
+            excluded_events,
+        })
+    }
+
+    #[must_use]
+    pub fn builder() -> EventSearchPayloadBuilder {
+        EventSearchPayloadBuilder::default()
+    }
+
+    #[must_use]
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut query_params: Vec<(&str, String)> = vec![];
+
+        // locale
+        if let Some(locale) = &self.locale {
+            query_params.push(("locale", locale.clone()));
+        }
+
+        // offset
+        if let Some(offset) = &self.offset {
+            query_params.push(("offset", offset.to_string()));
+        }
+
+        // limit
+        if let Some(limit) = &self.limit {
+            query_params.push(("limit", limit.to_string()));
+        }
+
+        // sort_by
+        if let Some(sort_by) = &self.sort_by {
+            query_params.push(("sort_by", sort_by.to_string()));
+        }
+
+        // sort_on
+        if let Some(sort_on) = &self.sort_on {
+            query_params.push(("sort_on", sort_on.to_string()));
+        }
+
+        // start_date
+        if let Some(start_date) = &self.start_date {
+            query_params.push(("start_date", start_date.to_string()));
+        }
+
+        // end_date
+        if let Some(end_date) = &self.end_date {
+            query_params.push(("end_date", end_date.to_string()));
+        }
+
+        // categories
+        if let Some(categories) = &self.categories {
+            query_params.push(("categories", categories.join(",")));
+        }
+
+        // is_free
+        if let Some(is_free) = &self.is_free {
+            query_params.push(("is_free", is_free.to_string()));
+        }
+
+        // location
+        if let Some(location) = &self.location {
+            query_params.push(("location", location.clone()));
+        }
+
+        // latitude
+        if let Some(coordinates) = &self.coordinates {
+            query_params.push(("latitude", coordinates.latitude.to_string()));
+            query_params.push(("longitude", coordinates.longitude.to_string()));
+        }
+
+        // radius
+        if let Some(radius) = &self.radius {
+            query_params.push(("radius", radius.to_string()));
+        }
+
+        // excluded_events
+        if let Some(excluded_events) = &self.excluded_events {
+            query_params.push(("excluded_events", excluded_events.join(",")));
+        }
+
+        query_params
+    }
+}
@@ -0,0 +1,167 @@
+use crate::endpoints::{EventSearchPayload, EventSearchPayloadError};
+use crate::models::{Coordinates, EventSortBy, EventSortOn};
+
+#[derive(Debug, Clone, Default)]
+pub struct EventSearchPayloadBuilder {
+    /// Specify the locale into which to localize the event information.
+    ///
+    /// See the list of [supported locales](https://www.yelp.com/developers/documentation/v3/supported_locales).
+    /// Defaults to `"en_US"`.
+    pub locale: Option<String>,
+
+    /// Offset the list of returned events by this amount.
+    pub offset: Option<usize>,
+
+    /// Number of events results to return.
+    ///
+    /// By default, it will return 3.
+    /// Maximum is 50.
+    pub limit: Option<usize>,
+
+    /// Sort by either descending or ascending order.
+    ///
+    /// By default, it returns results in descending order.
+    pub sort_by: Option<EventSortBy>,
+
+    /// Sort on popularity or time start.
+    ///
+    /// By default, sorts on popularity.
+    pub sort_on: Option<EventSortOn>,
+
+    /// Unix timestamp of the event start time.
+    ///
+    /// Will return events that only begin at or after the specified time.
+    pub start_date: Option<usize>,
+
+    /// Unix timestamp of the event end time.
+    ///
+    /// Will return events that only end at or before the specified time.
+    pub end_date: Option<usize>,
+
+    /// Categories to filter the events with.
+    ///
+    /// See the list of [supported event categories](https://www.yelp.com/developers/documentation/v3/event_categories).
+    pub categories: Option<Vec<String>>,
+
+    /// Filter whether the events are free to attend.
+    ///
+    /// By default no filter is applied, so both free and paid events will be returned.
+    pub is_free: Option<bool>,
+
+    /// Specifies the combination of "address, neighborhood, city, state or zip, optional country"
+    /// to be used when searching for events.
+    pub location: Option<String>,
+
+    /// Latitude and longitude of the location you want to search nearby.
+    ///
+    /// Cannot be used together with `location`.
+    pub coordinates: Option<Coordinates>,
+
+    /// Search radius in meters.
+    ///
+    /// If the value is too large, a `AREA_TOO_LARGE` error may be returned.
+    /// The max value is 40000 meters (25 miles).
+    pub radius: Option<usize>,
+
+    /// List of event ids.
+    ///
+    /// Events associated with these event ids in this list will not show up in the response.
+    pub excluded_events: Option<Vec<String>>,
+}
+
+impl EventSearchPayloadBuilder {
+    pub fn locale(&mut self, locale: String) -> &mut Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    pub fn offset(&mut self, offset: usize) -> &mut Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn sort_by(&mut self, sort_by: EventSortBy) -> &mut Self {
+        self.sort_by = Some(sort_by);
+        self
+    }
+
+    pub fn sort_on(&mut self, sort_on: EventSortOn) -> &mut Self {
+        self.sort_on = Some(sort_on);
+        self
+    }
+
+    pub fn start_date(&mut self, start_date: usize) -> &mut Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    pub fn end_date(&mut self, end_date: usize) -> &mut Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    pub fn categories(&mut self, categories: Vec<String>) -> &mut Self {
+        self.categories = Some(categories);
+        self
+    }
+
+    pub fn is_free(&mut self, is_free: bool) -> &mut Self {
+        self.is_free = Some(is_free);
+        self
+    }
+
+    pub fn location(&mut self, location: String) -> &mut Self {
+        self.location = Some(location);
+        self
+    }
+
+    pub fn coordinates(&mut self, coordinates: Coordinates) -> &mut Self {
+        self.coordinates = Some(coordinates);
+        self
+    }
+
+    pub fn radius(&mut self, radius: usize) -> &mut Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    pub fn excluded_events(&mut self, excluded_events: Vec<String>) -> &mut Self {
+        self.excluded_events = Some(excluded_events);
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns `EventSearchPayloadError::BothLocationAndLatLongSet` if both `location` and
+    /// `coordinates` are set. Only one or the other can be set.
+    ///
+    /// Returns `EventSearchPayloadError::RadiusTooLarge` if `radius` is over `40,000` meters
+    /// (~25 miles).
+    ///
+    /// Returns `EventSearchPayloadError::LimitTooLarge` if `limit` is over `50`.
+    ///
+    /// Returns `EventSearchPayloadError::EndDateBeforeStartDate` if `end_date` is before
+    /// `start_date`.
+    pub fn build(&self) -> Result<EventSearchPayload, EventSearchPayloadError> {
+        EventSearchPayload::new(
+            self.locale.clone(),
+            self.offset,
+            self.limit,
+            self.sort_by,
+            self.sort_on,
+            self.start_date,
+            self.end_date,
+            self.categories.clone(),
+            self.is_free,
+            self.location.clone(),
+            self.coordinates,
+            self.radius,
+            self.excluded_events.clone(),
+        )
+    }
+}
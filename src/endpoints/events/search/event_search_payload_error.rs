@@ -0,0 +1,42 @@
+use std::error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum EventSearchPayloadError {
+    /// Returned when both Location and Latitude/Longitude are set (must use either-or).
+    BothLocationAndLatLongSet,
+
+    /// Returned when `radius` is over `40,000` meters (approx. `25` miles).
+    RadiusTooLarge(usize),
+
+    /// Returned when `limit` is set over `50`.
+    LimitTooLarge(usize),
+
+    /// Returned when `end_date` is before `start_date`.
+    EndDateBeforeStartDate { start_date: usize, end_date: usize },
+}
+
+impl error::Error for EventSearchPayloadError {}
+
+impl Display for EventSearchPayloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BothLocationAndLatLongSet => {
+                write!(f, "can either set location OR latitude/longitude, NOT both")
+            }
+            Self::RadiusTooLarge(radius) => {
+                write!(f, "radius must not be above 40,000 meters: {radius}")
+            }
+            Self::LimitTooLarge(limit) => write!(f, "limit must not be over 50: {limit}"),
+            Self::EndDateBeforeStartDate {
+                start_date,
+                end_date,
+            } => {
+                write!(
+                    f,
+                    "end_date must not be before start_date: {end_date} < {start_date}"
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::models::Event;
+use serde::{Deserialize, Serialize};
+
+/// Response body from Event Search request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSearchResponse {
+    /// Total number of events Yelp finds based on the search criteria.
+    pub total: usize,
+
+    /// List of events Yelp finds based on the search criteria.
+    pub events: Vec<Event>,
+}
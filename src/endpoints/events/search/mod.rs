@@ -0,0 +1,68 @@
+mod event_search_payload;
+mod event_search_payload_builder;
+mod event_search_payload_error;
+mod event_search_response;
+
+use crate::error::Error;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use event_search_payload::*;
+pub use event_search_payload_builder::*;
+pub use event_search_payload_error::*;
+pub use event_search_response::*;
+
+impl YelpFusionClient {
+    /// Search for events based on search parameters.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `EventSearchResponse`.
+    pub async fn event_search(
+        &self,
+        payload: &EventSearchPayload,
+    ) -> Result<EventSearchResponse, Error> {
+        self.get("/events", &payload.to_query_params()).await
+    }
+
+    /// Same as `event_search`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `event_search`.
+    pub async fn event_search_with_meta(
+        &self,
+        payload: &EventSearchPayload,
+    ) -> Result<(EventSearchResponse, ResponseMeta), Error> {
+        self.get_with_meta("/events", &payload.to_query_params())
+            .await
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Search for events based on search parameters.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `EventSearchResponse`.
+    pub fn event_search(&self, payload: &EventSearchPayload) -> Result<EventSearchResponse, Error> {
+        self.get("/events", &payload.to_query_params())
+    }
+
+    /// Same as `event_search`, but also returns the `ResponseMeta` of the response, which
+    /// includes rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `event_search`.
+    pub fn event_search_with_meta(
+        &self,
+        payload: &EventSearchPayload,
+    ) -> Result<(EventSearchResponse, ResponseMeta), Error> {
+        self.get_with_meta("/events", &payload.to_query_params())
+    }
+}
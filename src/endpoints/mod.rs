@@ -1,3 +1,9 @@
 mod business;
+mod categories;
+mod endpoint;
+mod events;
 
 pub use business::*;
+pub use categories::*;
+pub use endpoint::*;
+pub use events::*;
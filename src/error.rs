@@ -1,8 +1,21 @@
-use reqwest::StatusCode;
+use crate::api_key::ApiKeyError;
+use crate::response_meta::ResponseMeta;
+use bytes::Bytes;
+use http::StatusCode;
+use http::header::{CONTENT_TYPE, HeaderMap};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
+use std::time::Duration;
 use std::{error, fmt};
 
+/// Maximum number of response body bytes kept in `Error::UnexpectedResponse` and
+/// `Error::InvalidResponseBody`.
+pub const MAX_ERROR_BODY_LEN: usize = 64 * 1024;
+
+/// Maximum number of response body characters shown when displaying `Error::UnexpectedResponse`.
+const MAX_ERROR_DISPLAY_LEN: usize = 200;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiErrorResponse {
     pub error: ApiError,
@@ -11,35 +24,309 @@ pub struct ApiErrorResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[expect(clippy::module_name_repetitions)]
 pub struct ApiError {
-    pub code: String,
+    pub code: ApiErrorCode,
     pub description: String,
+
+    /// The request field that failed validation.
+    ///
+    /// Only sent along with `ApiErrorCode::ValidationError`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+
+    /// The value of `field` that failed validation.
+    ///
+    /// Only sent along with `ApiErrorCode::ValidationError`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<serde_json::Value>,
+}
+
+/// Error codes returned by the Yelp Fusion API.
+///
+/// Codes this library doesn't know about yet are kept in `ApiErrorCode::Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum ApiErrorCode {
+    /// The API key has either expired or doesn't have the required scopes.
+    TokenInvalid,
+
+    /// The request was sent without an API key.
+    TokenMissing,
+
+    /// The API key is not authorized to access the requested resource.
+    UnauthorizedAccessToken,
+
+    /// The daily API request quota has been exhausted.
+    AccessLimitReached,
+
+    /// Too many requests have been sent in a short period of time.
+    TooManyRequestsPerSecond,
+
+    /// The location could not be geocoded.
+    LocationNotFound,
+
+    /// The search area is too large.
+    AreaTooLarge,
+
+    /// A request parameter failed validation; see `ApiError::field` and `ApiError::instance`.
+    ValidationError,
+
+    /// The requested business is not available, e.g. due to legal restrictions.
+    BusinessUnavailable,
+
+    /// The requested business could not be found.
+    BusinessNotFound,
+
+    /// The requested resource could not be found.
+    NotFound,
+
+    /// Something went wrong on Yelp's side.
+    InternalError,
+
+    /// Yelp is temporarily unavailable.
+    ServiceUnavailable,
+
+    /// Any other error code.
+    Unknown(String),
+}
+
+impl ApiErrorCode {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::TokenInvalid => "TOKEN_INVALID",
+            Self::TokenMissing => "TOKEN_MISSING",
+            Self::UnauthorizedAccessToken => "UNAUTHORIZED_ACCESS_TOKEN",
+            Self::AccessLimitReached => "ACCESS_LIMIT_REACHED",
+            Self::TooManyRequestsPerSecond => "TOO_MANY_REQUESTS_PER_SECOND",
+            Self::LocationNotFound => "LOCATION_NOT_FOUND",
+            Self::AreaTooLarge => "AREA_TOO_LARGE",
+            Self::ValidationError => "VALIDATION_ERROR",
+            Self::BusinessUnavailable => "BUSINESS_UNAVAILABLE",
+            Self::BusinessNotFound => "BUSINESS_NOT_FOUND",
+            Self::NotFound => "NOT_FOUND",
+            Self::InternalError => "INTERNAL_ERROR",
+            Self::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+            Self::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<String> for ApiErrorCode {
+    fn from(code: String) -> Self {
+        match code.as_str() {
+            "TOKEN_INVALID" => Self::TokenInvalid,
+            "TOKEN_MISSING" => Self::TokenMissing,
+            "UNAUTHORIZED_ACCESS_TOKEN" => Self::UnauthorizedAccessToken,
+            "ACCESS_LIMIT_REACHED" => Self::AccessLimitReached,
+            "TOO_MANY_REQUESTS_PER_SECOND" => Self::TooManyRequestsPerSecond,
+            "LOCATION_NOT_FOUND" => Self::LocationNotFound,
+            "AREA_TOO_LARGE" => Self::AreaTooLarge,
+            "VALIDATION_ERROR" => Self::ValidationError,
+            "BUSINESS_UNAVAILABLE" => Self::BusinessUnavailable,
+            "BUSINESS_NOT_FOUND" => Self::BusinessNotFound,
+            "NOT_FOUND" => Self::NotFound,
+            "INTERNAL_ERROR" => Self::InternalError,
+            "SERVICE_UNAVAILABLE" => Self::ServiceUnavailable,
+            _ => Self::Unknown(code),
+        }
+    }
+}
+
+impl From<ApiErrorCode> for String {
+    fn from(code: ApiErrorCode) -> Self {
+        match code {
+            ApiErrorCode::Unknown(code) => code,
+            code => code.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ApiErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 #[derive(Debug)]
 pub enum Error {
-    /// Error occurred while using the `reqwest` library.
-    ReqwestError(reqwest::Error),
-
     /// Error occurred while using the `serde` library.
     SerdeJsonError(serde_json::Error),
 
     /// An API request returned with a failed status code.
+    ///
+    /// `meta` holds the request ID and `RateLimit-*` values sent along with the response. It is
+    /// `None` when the error wasn't built by a client, e.g. by `GraphQlResponse::into_result`.
     RequestFailed {
         error_response: ApiErrorResponse,
         status_code: StatusCode,
+        meta: Option<Box<ResponseMeta>>,
+    },
+
+    /// An API request returned with a failed status code, but the response body wasn't one of
+    /// Yelp's error responses (e.g. an HTML page from a load balancer, or an empty body).
+    ///
+    /// `body` is truncated to `MAX_ERROR_BODY_LEN` bytes, and only its start is displayed.
+    UnexpectedResponse {
+        status_code: StatusCode,
+        headers: Box<HeaderMap>,
+        body: Bytes,
+        meta: Box<ResponseMeta>,
+    },
+
+    /// The daily quota of the client-side `RateLimiter` has been used up, so the request was not
+    /// sent.
+    LocalQuotaExhausted { daily_quota: u64 },
+
+    /// Every key of the client's `ApiKeyPool` is benched, so the request was not sent.
+    ///
+    /// `available_in` is how long until the first key becomes available again.
+    AllApiKeysBenched { available_in: Duration },
+
+    /// The `Transport` failed to send a request or to receive its response, or could not be
+    /// built.
+    ///
+    /// `retryable` tells whether sending the request again may succeed, e.g. after a timeout.
+    TransportError {
+        error: Box<dyn error::Error + Send + Sync>,
+        retryable: bool,
+    },
+
+    /// The URL of a request, built from the client's base URL, isn't valid.
+    InvalidUrl(url::ParseError),
+
+    /// The API key a request was to be sent with can't be used, so the request was not sent.
+    InvalidApiKey(ApiKeyError),
+
+    /// An API request succeeded, but its response body couldn't be deserialized.
+    ///
+    /// `body` is truncated to `MAX_ERROR_BODY_LEN` bytes.
+    InvalidResponseBody {
+        error: serde_json::Error,
+        status_code: StatusCode,
+        body: Bytes,
     },
 }
 
-impl error::Error for Error {}
+impl Error {
+    /// Returns the Yelp error code if this error is an `Error::RequestFailed`.
+    #[must_use]
+    pub const fn api_error_code(&self) -> Option<&ApiErrorCode> {
+        match self {
+            Self::RequestFailed { error_response, .. } => Some(&error_response.error.code),
+            _ => None,
+        }
+    }
+
+    /// Returns the `ResponseMeta` of the failed response if this error is an
+    /// `Error::RequestFailed` or an `Error::UnexpectedResponse`, e.g. to read the `RateLimit-*`
+    /// values of a `429` response.
+    #[must_use]
+    pub fn response_meta(&self) -> Option<&ResponseMeta> {
+        match self {
+            Self::RequestFailed { meta, .. } => meta.as_deref(),
+            Self::UnexpectedResponse { meta, .. } => Some(meta),
+            _ => None,
+        }
+    }
+
+    /// Attaches `meta` to an `Error::RequestFailed` that has none yet.
+    pub(crate) fn with_response_meta(mut self, response_meta: &ResponseMeta) -> Self {
+        if let Self::RequestFailed { meta, .. } = &mut self {
+            meta.get_or_insert_with(|| Box::new(response_meta.clone()));
+        }
+        self
+    }
+
+    /// Whether sending the same request again later may succeed.
+    ///
+    /// True for `TOO_MANY_REQUESTS_PER_SECOND`, `INTERNAL_ERROR` and `SERVICE_UNAVAILABLE`,
+    /// for `429`/`502`/`503`/`504` responses with an unknown error code, for connection errors
+    /// and timeouts, and for `TransportError`s flagged as retryable. Never true for other known
+    /// codes such as `ACCESS_LIMIT_REACHED`, `TOKEN_INVALID` or `VALIDATION_ERROR`.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::SerdeJsonError(_)
+            | Self::InvalidResponseBody { .. }
+            | Self::LocalQuotaExhausted { .. }
+            | Self::AllApiKeysBenched { .. }
+            | Self::InvalidUrl(_)
+            | Self::InvalidApiKey(_) => false,
+            Self::TransportError { retryable, .. } => *retryable,
+            Self::UnexpectedResponse { status_code, .. } => is_retryable_status_code(*status_code),
+            Self::RequestFailed {
+                error_response,
+                status_code,
+                ..
+            } => match error_response.error.code {
+                ApiErrorCode::TooManyRequestsPerSecond
+                | ApiErrorCode::InternalError
+                | ApiErrorCode::ServiceUnavailable => true,
+                ApiErrorCode::Unknown(_) => is_retryable_status_code(*status_code),
+                _ => false,
+            },
+        }
+    }
+
+    /// Whether the API key is missing, invalid or not authorized for the request.
+    #[must_use]
+    pub const fn is_auth_error(&self) -> bool {
+        matches!(
+            self.api_error_code(),
+            Some(
+                ApiErrorCode::TokenInvalid
+                    | ApiErrorCode::TokenMissing
+                    | ApiErrorCode::UnauthorizedAccessToken
+            )
+        )
+    }
+
+    /// Whether the daily API request quota has been exhausted, either according to Yelp
+    /// (`ACCESS_LIMIT_REACHED`) or to the client-side `RateLimiter`.
+    #[must_use]
+    pub const fn is_quota_error(&self) -> bool {
+        matches!(self, Self::LocalQuotaExhausted { .. })
+            || matches!(
+                self.api_error_code(),
+                Some(ApiErrorCode::AccessLimitReached)
+            )
+    }
+}
+
+const fn is_retryable_status_code(status_code: StatusCode) -> bool {
+    matches!(
+        status_code,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::SerdeJsonError(e) | Self::InvalidResponseBody { error: e, .. } => Some(e),
+            Self::RequestFailed { .. }
+            | Self::UnexpectedResponse { .. }
+            | Self::LocalQuotaExhausted { .. }
+            | Self::AllApiKeysBenched { .. } => None,
+            Self::TransportError { error, .. } => Some(error.as_ref()),
+            Self::InvalidUrl(e) => Some(e),
+            Self::InvalidApiKey(e) => Some(e),
+        }
+    }
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Self::ReqwestError(e) => fmt::Display::fmt(e, f),
             Self::SerdeJsonError(e) => fmt::Display::fmt(e, f),
             Self::RequestFailed {
                 error_response,
                 status_code,
+                ..
             } => {
                 write!(
                     f,
@@ -47,13 +334,48 @@ impl fmt::Display for Error {
                     status_code, error_response.error.code, error_response.error.description
                 )
             }
+            Self::UnexpectedResponse {
+                status_code,
+                headers,
+                body,
+                ..
+            } => {
+                let content_type: &str = headers
+                    .get(CONTENT_TYPE)
+                    .and_then(|content_type| content_type.to_str().ok())
+                    .unwrap_or("unknown content type");
+                write!(
+                    f,
+                    "[{status_code}] unexpected response ({content_type}, {} bytes): ",
+                    body.len()
+                )?;
+                let body: Cow<'_, str> = String::from_utf8_lossy(body);
+                match body.char_indices().nth(MAX_ERROR_DISPLAY_LEN) {
+                    Some((end, _)) => write!(f, "{}...", &body[..end]),
+                    None => write!(f, "{body}"),
+                }
+            }
+            Self::LocalQuotaExhausted { daily_quota } => {
+                write!(f, "local daily quota of {daily_quota} requests exhausted")
+            }
+            Self::TransportError { error, .. } => write!(f, "transport error: {error}"),
+            Self::InvalidUrl(e) => write!(f, "invalid request URL: {e}"),
+            Self::InvalidApiKey(e) => write!(f, "invalid API key: {e}"),
+            Self::AllApiKeysBenched { available_in } => {
+                write!(f, "all API keys are benched for another {available_in:?}")
+            }
+            Self::InvalidResponseBody {
+                error, status_code, ..
+            } => {
+                write!(f, "[{status_code}] invalid response body: {error}")
+            }
         }
     }
 }
 
-impl From<reqwest::Error> for Error {
-    fn from(e: reqwest::Error) -> Self {
-        Self::ReqwestError(e)
+impl From<url::ParseError> for Error {
+    fn from(e: url::ParseError) -> Self {
+        Self::InvalidUrl(e)
     }
 }
 
@@ -0,0 +1,60 @@
+use crate::graphql::{GraphQlLocation, GraphQlReviews};
+use crate::models::{Category, Coordinates, Hours, PriceType};
+use serde::{Deserialize, Serialize};
+
+/// A Yelp business, as returned by the GraphQL query helpers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphQlBusiness {
+    /// Unique Yelp ID of this business.
+    pub id: String,
+
+    /// Unique Yelp alias of this business.
+    pub alias: Option<String>,
+
+    /// Name of this business.
+    pub name: Option<String>,
+
+    /// URL for business page on Yelp.
+    pub url: Option<String>,
+
+    /// Phone number of the business.
+    pub phone: Option<String>,
+
+    /// Phone number of the business formatted nicely to be displayed to users.
+    pub display_phone: Option<String>,
+
+    /// Rating for this business (value ranges from 1, 1.5, ... 4.5, 5).
+    pub rating: Option<f32>,
+
+    /// Number of reviews for this business.
+    pub review_count: Option<usize>,
+
+    /// Price level of the business.
+    pub price: Option<PriceType>,
+
+    /// Whether business has been (permanently) closed.
+    pub is_closed: Option<bool>,
+
+    /// URLs of up to three photos of the business.
+    #[serde(default)]
+    pub photos: Vec<String>,
+
+    /// Coordinates of this business.
+    pub coordinates: Option<Coordinates>,
+
+    /// Location of this business.
+    pub location: Option<GraphQlLocation>,
+
+    /// List of category title and alias pairs associated with this business.
+    #[serde(default)]
+    pub categories: Vec<Category>,
+
+    /// Opening hours of the business.
+    #[serde(default)]
+    pub hours: Vec<Hours>,
+
+    /// Reviews of this business.
+    ///
+    /// Only present when reviews were requested.
+    pub reviews: Option<GraphQlReviews>,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// An entry of the `errors` array of a GraphQL response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphQlError {
+    /// Human readable description of the error.
+    pub message: String,
+
+    /// Path of the response field which produced the error, e.g. `["b3"]`.
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+
+    /// Additional, Yelp specific information about the error.
+    pub extensions: Option<GraphQlErrorExtensions>,
+}
+
+/// Yelp specific information attached to a GraphQL error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphQlErrorExtensions {
+    /// Yelp error code, e.g. `BUSINESS_NOT_FOUND`.
+    pub code: Option<String>,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// The location of a business, as returned by the GraphQL API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphQlLocation {
+    /// Street address of this business.
+    pub address1: Option<String>,
+
+    /// Street address of this business, continued.
+    pub address2: Option<String>,
+
+    /// Street address of this business, continued.
+    pub address3: Option<String>,
+
+    /// City of this business.
+    pub city: Option<String>,
+
+    /// ISO 3166-2 (with a few exceptions) state code of this business.
+    pub state: Option<String>,
+
+    /// Postal code of this business.
+    pub postal_code: Option<String>,
+
+    /// ISO 3166-1 alpha-2 country code of this business.
+    pub country: Option<String>,
+
+    /// Address formatted in the standard address format for the business's country.
+    pub formatted_address: Option<String>,
+}
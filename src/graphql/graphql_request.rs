@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Request body posted to the GraphQL endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphQlRequest {
+    /// The GraphQL query document.
+    pub query: String,
+
+    /// Values for the variables declared in `query`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<serde_json::Value>,
+}
+
+impl GraphQlRequest {
+    #[must_use]
+    pub const fn new(query: String, variables: Option<serde_json::Value>) -> Self {
+        Self { query, variables }
+    }
+}
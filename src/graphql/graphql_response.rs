@@ -0,0 +1,63 @@
+use crate::error::{ApiError, ApiErrorCode, ApiErrorResponse, Error};
+use crate::graphql::GraphQlError;
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// Response body from a GraphQL request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphQlResponse<T> {
+    /// The requested data, if the query could be (at least partially) executed.
+    pub data: Option<T>,
+
+    /// Errors raised while executing the query.
+    #[serde(default)]
+    pub errors: Vec<GraphQlError>,
+}
+
+impl<T> GraphQlResponse<T> {
+    /// Returns `data` if the response carries no errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::RequestFailed` if the `errors` array is not empty, using the first error's
+    /// code (or `GRAPHQL_ERROR` when Yelp didn't send one) and every error message.
+    /// Also returns `Error::RequestFailed` if the response contains neither data nor errors.
+    pub fn into_result(self) -> Result<T, Error> {
+        if let Some(first_error) = self.errors.first() {
+            let code: String = first_error
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.code.clone())
+                .unwrap_or_else(|| String::from("GRAPHQL_ERROR"));
+            let description: String = self
+                .errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<&str>>()
+                .join("; ");
+            return Err(request_failed(code, description));
+        }
+
+        self.data.ok_or_else(|| {
+            request_failed(
+                String::from("GRAPHQL_NO_DATA"),
+                String::from("response contained neither data nor errors"),
+            )
+        })
+    }
+}
+
+fn request_failed(code: String, description: String) -> Error {
+    Error::RequestFailed {
+        error_response: ApiErrorResponse {
+            error: ApiError {
+                code: ApiErrorCode::from(code),
+                description,
+                field: None,
+                instance: None,
+            },
+        },
+        status_code: StatusCode::OK,
+        meta: None,
+    }
+}
@@ -0,0 +1,17 @@
+use crate::models::Review;
+use serde::{Deserialize, Serialize};
+
+/// Reviews of a business, as returned by the GraphQL API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphQlReviews {
+    /// The total number of reviews that the business has.
+    pub total: usize,
+
+    /// The requested page of reviews.
+    #[serde(default)]
+    pub review: Vec<Review>,
+
+    /// A list of languages for which the business has at least one review.
+    #[serde(default)]
+    pub possible_languages: Vec<String>,
+}
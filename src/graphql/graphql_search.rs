@@ -0,0 +1,13 @@
+use crate::graphql::GraphQlBusiness;
+use serde::{Deserialize, Serialize};
+
+/// Result of the GraphQL `search` query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphQlSearch {
+    /// Total number of business Yelp finds based on the search criteria.
+    pub total: usize,
+
+    /// List of business Yelp finds based on the search criteria.
+    #[serde(default)]
+    pub business: Vec<GraphQlBusiness>,
+}
@@ -0,0 +1,235 @@
+//! Client for Yelp's [GraphQL API](https://docs.developer.yelp.com/docs/graphql-intro).
+//!
+//! Besides sending arbitrary queries through `graphql`, typed helpers are provided for the
+//! `business`, `search` and `reviews` queries. `graphql_businesses` fetches many businesses (and
+//! optionally their reviews) in a single round trip, instead of one REST call per business.
+
+mod graphql_business;
+mod graphql_error;
+mod graphql_location;
+mod graphql_request;
+mod graphql_response;
+mod graphql_reviews;
+mod graphql_search;
+mod queries;
+
+use crate::endpoints::BusinessSearchPayload;
+use crate::error::Error;
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+pub use graphql_business::*;
+pub use graphql_error::*;
+pub use graphql_location::*;
+pub use graphql_request::*;
+pub use graphql_response::*;
+pub use graphql_reviews::*;
+pub use graphql_search::*;
+use queries::{BusinessData, BusinessesData, ReviewsData, SearchData};
+use serde::de::DeserializeOwned;
+
+impl YelpFusionClient {
+    /// Sends an arbitrary GraphQL query and deserializes its `data` into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, if the response contained GraphQL `errors`, or if it failed to serialize the
+    /// response's `data` into `T`.
+    pub async fn graphql<T: DeserializeOwned>(&self, request: &GraphQlRequest) -> Result<T, Error> {
+        self.graphql_with_meta(request).await.map(|(data, _)| data)
+    }
+
+    /// Same as `graphql`, but also returns the `ResponseMeta` of the response, which includes
+    /// rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`.
+    pub async fn graphql_with_meta<T: DeserializeOwned>(
+        &self,
+        request: &GraphQlRequest,
+    ) -> Result<(T, ResponseMeta), Error> {
+        let (response, meta): (GraphQlResponse<T>, ResponseMeta) =
+            self.post_with_meta("/graphql", request).await?;
+        match response.into_result() {
+            Ok(data) => Ok((data, meta)),
+            Err(e) => Err(e.with_response_meta(&meta)),
+        }
+    }
+
+    /// Fetches a single business, and up to `review_limit` of its reviews when set.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`.
+    pub async fn graphql_business(
+        &self,
+        id: &str,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlBusiness, Error> {
+        let data: BusinessData = self
+            .graphql(&queries::business_request(id, review_limit))
+            .await?;
+        Ok(data.business)
+    }
+
+    /// Fetches many businesses, and up to `review_limit` of their reviews when set, in a single
+    /// request.
+    ///
+    /// Results are returned in the order of `ids`; businesses Yelp could not find are `None`.
+    /// Keep batches reasonably small, since Yelp rejects overly complex queries.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`, except that errors for
+    /// individual businesses are reported as `None` as long as Yelp returned any data.
+    pub async fn graphql_businesses(
+        &self,
+        ids: &[String],
+        review_limit: Option<usize>,
+    ) -> Result<Vec<Option<GraphQlBusiness>>, Error> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let (response, meta): (GraphQlResponse<BusinessesData>, ResponseMeta) = self
+            .post_with_meta("/graphql", &queries::businesses_request(ids, review_limit))
+            .await?;
+        queries::businesses_from_response(ids.len(), response)
+            .map_err(|e| e.with_response_meta(&meta))
+    }
+
+    /// Searches for businesses, and up to `review_limit` of their reviews when set, in a single
+    /// request.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`.
+    pub async fn graphql_search(
+        &self,
+        payload: &BusinessSearchPayload,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlSearch, Error> {
+        let data: SearchData = self
+            .graphql(&queries::search_request(payload, review_limit))
+            .await?;
+        Ok(data.search)
+    }
+
+    /// Fetches a page of reviews of a business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`.
+    pub async fn graphql_reviews(
+        &self,
+        id: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<GraphQlReviews, Error> {
+        let data: ReviewsData = self
+            .graphql(&queries::reviews_request(id, limit, offset))
+            .await?;
+        Ok(data.business.reviews)
+    }
+}
+
+impl BlockingYelpFusionClient {
+    /// Sends an arbitrary GraphQL query and deserializes its `data` into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, if the response contained GraphQL `errors`, or if it failed to serialize the
+    /// response's `data` into `T`.
+    pub fn graphql<T: DeserializeOwned>(&self, request: &GraphQlRequest) -> Result<T, Error> {
+        self.graphql_with_meta(request).map(|(data, _)| data)
+    }
+
+    /// Same as `graphql`, but also returns the `ResponseMeta` of the response, which includes
+    /// rate limit information.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`.
+    pub fn graphql_with_meta<T: DeserializeOwned>(
+        &self,
+        request: &GraphQlRequest,
+    ) -> Result<(T, ResponseMeta), Error> {
+        let (response, meta): (GraphQlResponse<T>, ResponseMeta) =
+            self.post_with_meta("/graphql", request)?;
+        match response.into_result() {
+            Ok(data) => Ok((data, meta)),
+            Err(e) => Err(e.with_response_meta(&meta)),
+        }
+    }
+
+    /// Fetches a single business, and up to `review_limit` of its reviews when set.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`.
+    pub fn graphql_business(
+        &self,
+        id: &str,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlBusiness, Error> {
+        let data: BusinessData = self.graphql(&queries::business_request(id, review_limit))?;
+        Ok(data.business)
+    }
+
+    /// Fetches many businesses, and up to `review_limit` of their reviews when set, in a single
+    /// request.
+    ///
+    /// Results are returned in the order of `ids`; businesses Yelp could not find are `None`.
+    /// Keep batches reasonably small, since Yelp rejects overly complex queries.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`, except that errors for
+    /// individual businesses are reported as `None` as long as Yelp returned any data.
+    pub fn graphql_businesses(
+        &self,
+        ids: &[String],
+        review_limit: Option<usize>,
+    ) -> Result<Vec<Option<GraphQlBusiness>>, Error> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let (response, meta): (GraphQlResponse<BusinessesData>, ResponseMeta) =
+            self.post_with_meta("/graphql", &queries::businesses_request(ids, review_limit))?;
+        queries::businesses_from_response(ids.len(), response)
+            .map_err(|e| e.with_response_meta(&meta))
+    }
+
+    /// Searches for businesses, and up to `review_limit` of their reviews when set, in a single
+    /// request.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`.
+    pub fn graphql_search(
+        &self,
+        payload: &BusinessSearchPayload,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlSearch, Error> {
+        let data: SearchData = self.graphql(&queries::search_request(payload, review_limit))?;
+        Ok(data.search)
+    }
+
+    /// Fetches a page of reviews of a business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `graphql`.
+    pub fn graphql_reviews(
+        &self,
+        id: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<GraphQlReviews, Error> {
+        let data: ReviewsData = self.graphql(&queries::reviews_request(id, limit, offset))?;
+        Ok(data.business.reviews)
+    }
+}
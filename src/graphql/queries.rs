@@ -0,0 +1,164 @@
+use crate::endpoints::BusinessSearchPayload;
+use crate::error::Error;
+use crate::graphql::{
+    GraphQlBusiness, GraphQlRequest, GraphQlResponse, GraphQlReviews, GraphQlSearch,
+};
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Fields selected for every business returned by the query helpers.
+const BUSINESS_FRAGMENT: &str = "fragment BusinessFields on Business { id alias name url phone \
+    display_phone rating review_count price is_closed photos coordinates { latitude longitude } \
+    location { address1 address2 address3 city state postal_code country formatted_address } \
+    categories { alias title } hours { hours_type is_open_now open { day start end is_overnight } } }";
+
+/// Fields selected for every page of reviews returned by the query helpers.
+const REVIEWS_FIELDS: &str = "total possible_languages review { id url text rating time_created \
+    user { id name profile_url image_url } }";
+
+#[derive(Deserialize)]
+pub(crate) struct BusinessData {
+    pub(crate) business: GraphQlBusiness,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SearchData {
+    pub(crate) search: GraphQlSearch,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ReviewsData {
+    pub(crate) business: ReviewsBusiness,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ReviewsBusiness {
+    pub(crate) reviews: GraphQlReviews,
+}
+
+pub(crate) type BusinessesData = HashMap<String, Option<GraphQlBusiness>>;
+
+/// Selection for a single business, optionally including its reviews.
+fn business_selection() -> String {
+    format!(
+        "...BusinessFields reviews(limit: $review_limit) @include(if: $with_reviews) {{ {REVIEWS_FIELDS} }}"
+    )
+}
+
+fn review_variables(variables: &mut Map<String, Value>, review_limit: Option<usize>) {
+    variables.insert(String::from("with_reviews"), json!(review_limit.is_some()));
+    variables.insert(String::from("review_limit"), json!(review_limit));
+}
+
+pub(crate) fn business_request(id: &str, review_limit: Option<usize>) -> GraphQlRequest {
+    let query: String = format!(
+        "query Business($id: String!, $review_limit: Int, $with_reviews: Boolean!) {{ \
+         business(id: $id) {{ {} }} }} {BUSINESS_FRAGMENT}",
+        business_selection()
+    );
+
+    let mut variables: Map<String, Value> = Map::new();
+    variables.insert(String::from("id"), json!(id));
+    review_variables(&mut variables, review_limit);
+
+    GraphQlRequest::new(query, Some(Value::Object(variables)))
+}
+
+pub(crate) fn businesses_request(ids: &[String], review_limit: Option<usize>) -> GraphQlRequest {
+    let mut declarations: String = String::new();
+    let mut selections: String = String::new();
+    for i in 0..ids.len() {
+        let _ = write!(declarations, "$id{i}: String!, ");
+        let _ = write!(
+            selections,
+            "b{i}: business(id: $id{i}) {{ {} }} ",
+            business_selection()
+        );
+    }
+    let query: String = format!(
+        "query Businesses({declarations}$review_limit: Int, $with_reviews: Boolean!) {{ \
+         {selections}}} {BUSINESS_FRAGMENT}"
+    );
+
+    let mut variables: Map<String, Value> = Map::new();
+    for (i, id) in ids.iter().enumerate() {
+        variables.insert(format!("id{i}"), json!(id));
+    }
+    review_variables(&mut variables, review_limit);
+
+    GraphQlRequest::new(query, Some(Value::Object(variables)))
+}
+
+/// Orders the aliased businesses (`b0`, `b1`, ...) of a batch response like the requested ids.
+///
+/// Businesses that Yelp could not resolve are returned as `None` instead of failing the whole
+/// batch.
+pub(crate) fn businesses_from_response(
+    count: usize,
+    response: GraphQlResponse<BusinessesData>,
+) -> Result<Vec<Option<GraphQlBusiness>>, Error> {
+    let mut data: BusinessesData = match response.data {
+        Some(data) => data,
+        None => return response.into_result().map(|_| vec![]),
+    };
+    Ok((0..count)
+        .map(|i| data.remove(&format!("b{i}")).flatten())
+        .collect())
+}
+
+pub(crate) fn search_request(
+    payload: &BusinessSearchPayload,
+    review_limit: Option<usize>,
+) -> GraphQlRequest {
+    let query: String = format!(
+        "query Search($term: String, $location: String, $latitude: Float, $longitude: Float, \
+         $radius: Float, $categories: String, $locale: String, $limit: Int, $offset: Int, \
+         $sort_by: String, $price: String, $open_now: Boolean, $open_at: Int, \
+         $attributes: [String], $review_limit: Int, $with_reviews: Boolean!) {{ \
+         search(term: $term, location: $location, latitude: $latitude, longitude: $longitude, \
+         radius: $radius, categories: $categories, locale: $locale, limit: $limit, \
+         offset: $offset, sort_by: $sort_by, price: $price, open_now: $open_now, \
+         open_at: $open_at, attributes: $attributes) {{ total business {{ {} }} }} }} \
+         {BUSINESS_FRAGMENT}",
+        business_selection()
+    );
+
+    let mut variables: Map<String, Value> = Map::new();
+    for (key, value) in payload.to_query_params() {
+        let value: Value = match key {
+            "latitude" | "longitude" | "radius" => value
+                .parse::<f64>()
+                .map_or(json!(value), |value| json!(value)),
+            "limit" | "offset" | "open_at" => value
+                .parse::<u64>()
+                .map_or(json!(value), |value| json!(value)),
+            "open_now" => json!(value == "true"),
+            "attributes" => json!(value.split(',').collect::<Vec<&str>>()),
+            _ => json!(value),
+        };
+        variables.insert(key.to_string(), value);
+    }
+    review_variables(&mut variables, review_limit);
+
+    GraphQlRequest::new(query, Some(Value::Object(variables)))
+}
+
+pub(crate) fn reviews_request(
+    id: &str,
+    limit: Option<usize>,
+    offset: Option<usize>,
+) -> GraphQlRequest {
+    let query: String = format!(
+        "query Reviews($id: String!, $limit: Int, $offset: Int) {{ \
+         business(id: $id) {{ reviews(limit: $limit, offset: $offset) {{ {REVIEWS_FIELDS} }} }} }}"
+    );
+
+    let mut variables: Map<String, Value> = Map::new();
+    variables.insert(String::from("id"), json!(id));
+    variables.insert(String::from("limit"), json!(limit));
+    variables.insert(String::from("offset"), json!(offset));
+
+    GraphQlRequest::new(query, Some(Value::Object(variables)))
+}
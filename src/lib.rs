@@ -16,7 +16,8 @@
 //! async fn main() -> Result<(), Error> {
 //!     let yelp_fusion_api_key = env::var("YELP_FUSION_API_KEY").expect("no Yelp Fusion API key");
 //!
-//!     let yelp_fusion_client: YelpFusionClient = YelpFusionClient::new(yelp_fusion_api_key, None);
+//!     let yelp_fusion_client: YelpFusionClient =
+//!         YelpFusionClient::builder(yelp_fusion_api_key).build()?;
 //!     let business_search_payload: BusinessSearchPayload = BusinessSearchPayload::builder()
 //!         .coordinates(Coordinates::new(37.772_484, -122.396_68))
 //!         .radius(1609)
@@ -44,10 +45,24 @@
 //! ```
 //!
 //! For more examples, check out the `examples` directory within the repository.
+//!
+//! ## Features
+//!
+//! - `reqwest` (default): sends requests with `reqwest` through `ReqwestTransport` and
+//!   `BlockingReqwestTransport`. Without it, clients are built with a custom `Transport` (or
+//!   `BlockingTransport`) passed to their builder's `transport`.
 
 extern crate core;
 
+pub mod api_key;
+pub mod cache;
 pub mod endpoints;
 pub mod error;
+pub mod graphql;
 pub mod models;
+pub mod rate_limiter;
+pub mod response_meta;
+pub mod retry;
+pub mod transport;
+pub mod yelp_api;
 pub mod yelp_fusion;
@@ -35,6 +35,18 @@ pub enum Attribute {
 }
 
 impl Attribute {
+    /// Every variant, in declaration order.
+    pub const ALL: [Self; 8] = [
+        Self::HotAndNew,
+        Self::RequestAQuote,
+        Self::Reservation,
+        Self::WaitlistReservation,
+        Self::Deals,
+        Self::GenderNeutralRestrooms,
+        Self::OpenToAll,
+        Self::WheelchairAccessible,
+    ];
+
     #[must_use]
     pub const fn as_str(&self) -> &str {
         match self {
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// A business suggested by the Autocomplete endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutocompleteBusiness {
+    /// Unique Yelp ID of this business.
+    ///
+    /// Example: '`4kMBvIEWPxWkWKFN__8SxQ`'
+    pub id: String,
+
+    /// Name of this business.
+    pub name: String,
+}
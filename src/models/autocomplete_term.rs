@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// A search term suggested by the Autocomplete endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutocompleteTerm {
+    /// The text content of the term.
+    pub text: String,
+}
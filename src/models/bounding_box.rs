@@ -0,0 +1,99 @@
+use crate::models::Coordinates;
+use serde::{Deserialize, Serialize};
+
+/// Meters per degree of latitude (and of longitude at the equator).
+const METERS_PER_DEGREE: f64 = 111_320.0;
+
+/// Rectangular area between two corners.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct BoundingBox {
+    /// Corner with the lowest latitude and longitude.
+    pub south_west: Coordinates,
+
+    /// Corner with the highest latitude and longitude.
+    pub north_east: Coordinates,
+}
+
+impl BoundingBox {
+    #[must_use]
+    pub const fn new(south_west: Coordinates, north_east: Coordinates) -> Self {
+        Self {
+            south_west,
+            north_east,
+        }
+    }
+
+    /// Smallest box containing the circle of `radius` meters around `center`.
+    #[must_use]
+    #[expect(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn around(center: Coordinates, radius: usize) -> Self {
+        let radius: f64 = radius as f64;
+        let latitude: f64 = f64::from(center.latitude);
+        let delta_latitude: f64 = radius / METERS_PER_DEGREE;
+        let delta_longitude: f64 = (radius
+            / (METERS_PER_DEGREE * latitude.to_radians().cos().max(f64::EPSILON)))
+        .min(180.0);
+
+        Self {
+            south_west: Coordinates::new(
+                (latitude - delta_latitude).max(-90.0) as f32,
+                (f64::from(center.longitude) - delta_longitude) as f32,
+            ),
+            north_east: Coordinates::new(
+                (latitude + delta_latitude).min(90.0) as f32,
+                (f64::from(center.longitude) + delta_longitude) as f32,
+            ),
+        }
+    }
+
+    #[must_use]
+    pub fn center(&self) -> Coordinates {
+        Coordinates::new(
+            self.south_west.latitude.midpoint(self.north_east.latitude),
+            self.south_west
+                .longitude
+                .midpoint(self.north_east.longitude),
+        )
+    }
+
+    /// Distance from the center to the farthest corner, in meters, i.e. the radius of the
+    /// smallest circle around the center covering the whole box.
+    #[must_use]
+    pub fn circumradius(&self) -> f64 {
+        let center: Coordinates = self.center();
+        [
+            self.south_west,
+            self.north_east,
+            Coordinates::new(self.south_west.latitude, self.north_east.longitude),
+            Coordinates::new(self.north_east.latitude, self.south_west.longitude),
+        ]
+        .iter()
+        .map(|corner| center.distance_to(corner))
+        .fold(0.0, f64::max)
+    }
+
+    #[must_use]
+    pub fn contains(&self, coordinates: &Coordinates) -> bool {
+        (self.south_west.latitude..=self.north_east.latitude).contains(&coordinates.latitude)
+            && (self.south_west.longitude..=self.north_east.longitude)
+                .contains(&coordinates.longitude)
+    }
+
+    /// Splits the box into its four quarters.
+    #[must_use]
+    pub fn quadrants(&self) -> [Self; 4] {
+        let center: Coordinates = self.center();
+        [
+            Self::new(self.south_west, center),
+            Self::new(
+                Coordinates::new(self.south_west.latitude, center.longitude),
+                Coordinates::new(center.latitude, self.north_east.longitude),
+            ),
+            Self::new(
+                Coordinates::new(center.latitude, self.south_west.longitude),
+                Coordinates::new(self.north_east.latitude, center.longitude),
+            ),
+            Self::new(center, self.north_east),
+        ]
+    }
+}
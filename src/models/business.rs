@@ -61,7 +61,9 @@ pub struct Business {
 
     /// Distance in meters from the search location.
     /// This returns meters regardless of the locale.
-    pub distance: f32,
+    ///
+    /// Only present when the search was made relative to a location (e.g. not for Phone Search).
+    pub distance: Option<f32>,
 
     /// Suggested area in a map to display results in.
     pub region: Option<Region>,
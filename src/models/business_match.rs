@@ -0,0 +1,29 @@
+use crate::models::{Coordinates, Location};
+use serde::{Deserialize, Serialize};
+
+/// A Yelp business returned by the Business Match endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessMatch {
+    /// Unique Yelp ID of this business.
+    ///
+    /// Example: '`4kMBvIEWPxWkWKFN__8SxQ`'
+    pub id: String,
+
+    /// Unique Yelp alias of this business.
+    ///
+    /// Can contain unicode characters.
+    /// Example: 'yelp-san-francisco'.
+    pub alias: String,
+
+    /// Name of this business.
+    pub name: String,
+
+    /// Location of this business, including address, city, state, zip code and country.
+    pub location: Location,
+
+    /// Coordinates of this business.
+    pub coordinates: Coordinates,
+
+    /// Phone number of the business.
+    pub phone: String,
+}
@@ -8,4 +8,22 @@ pub struct Category {
 
     /// Title of a category for display purpose.
     pub title: String,
+
+    /// List of aliases of the parent categories.
+    ///
+    /// Only returned by the Categories endpoints.
+    #[serde(default)]
+    pub parent_aliases: Vec<String>,
+
+    /// Countries for which this category is whitelisted.
+    ///
+    /// Only returned by the Categories endpoints.
+    #[serde(default)]
+    pub country_whitelist: Vec<String>,
+
+    /// Countries for which this category is blacklisted.
+    ///
+    /// Only returned by the Categories endpoints.
+    #[serde(default)]
+    pub country_blacklist: Vec<String>,
 }
@@ -0,0 +1,105 @@
+use crate::models::Category;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Parent/child hierarchy built from the flat list of categories returned by the All Categories
+/// endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct CategoryTree {
+    /// Every category in the tree, keyed by alias.
+    categories: HashMap<String, Category>,
+
+    /// Aliases of the direct children of each category, sorted by alias.
+    children: HashMap<String, Vec<String>>,
+}
+
+impl CategoryTree {
+    #[must_use]
+    pub fn new(categories: Vec<Category>) -> Self {
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        for category in &categories {
+            for parent_alias in &category.parent_aliases {
+                children
+                    .entry(parent_alias.clone())
+                    .or_default()
+                    .push(category.alias.clone());
+            }
+        }
+        for aliases in children.values_mut() {
+            aliases.sort();
+            aliases.dedup();
+        }
+
+        Self {
+            categories: categories
+                .into_iter()
+                .map(|category| (category.alias.clone(), category))
+                .collect(),
+            children,
+        }
+    }
+
+    /// Returns the category with the given alias, if it is part of the tree.
+    #[must_use]
+    pub fn get(&self, alias: &str) -> Option<&Category> {
+        self.categories.get(alias)
+    }
+
+    /// Returns the top-level categories (those without a known parent), sorted by alias.
+    #[must_use]
+    pub fn roots(&self) -> Vec<&Category> {
+        let mut roots: Vec<&Category> = self
+            .categories
+            .values()
+            .filter(|category| {
+                category
+                    .parent_aliases
+                    .iter()
+                    .all(|parent_alias| !self.categories.contains_key(parent_alias))
+            })
+            .collect();
+        roots.sort_by(|a, b| a.alias.cmp(&b.alias));
+        roots
+    }
+
+    /// Returns the direct children of the category with the given alias, sorted by alias.
+    #[must_use]
+    pub fn children(&self, alias: &str) -> Vec<&Category> {
+        self.children
+            .get(alias)
+            .into_iter()
+            .flatten()
+            .filter_map(|child_alias| self.categories.get(child_alias))
+            .collect()
+    }
+
+    /// Returns `alias` followed by the aliases of all of its descendants, breadth-first.
+    ///
+    /// The result can be passed straight to `BusinessSearchPayloadBuilder::categories` to search
+    /// a category and everything beneath it. Returns an empty list if `alias` is unknown.
+    #[must_use]
+    pub fn descendant_aliases(&self, alias: &str) -> Vec<String> {
+        if !self.categories.contains_key(alias) {
+            return vec![];
+        }
+
+        let mut aliases: Vec<String> = vec![];
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut queue: VecDeque<&str> = VecDeque::from([alias]);
+        while let Some(current) = queue.pop_front() {
+            if !visited.insert(current) {
+                continue;
+            }
+            aliases.push(current.to_string());
+            for child_alias in self.children.get(current).into_iter().flatten() {
+                queue.push_back(child_alias);
+            }
+        }
+        aliases
+    }
+}
+
+impl From<Vec<Category>> for CategoryTree {
+    fn from(categories: Vec<Category>) -> Self {
+        Self::new(categories)
+    }
+}
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+/// Mean radius of the Earth, in meters.
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
 /// The coordinates of this business.
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub struct Coordinates {
@@ -18,4 +21,19 @@ impl Coordinates {
             longitude,
         }
     }
+
+    /// Great-circle distance to `other`, in meters.
+    #[must_use]
+    pub fn distance_to(&self, other: &Self) -> f64 {
+        let (latitude, other_latitude) = (
+            f64::from(self.latitude).to_radians(),
+            f64::from(other.latitude).to_radians(),
+        );
+        let delta_latitude: f64 = other_latitude - latitude;
+        let delta_longitude: f64 = f64::from(other.longitude - self.longitude).to_radians();
+
+        let a: f64 = (delta_latitude / 2.0).sin().powi(2)
+            + latitude.cos() * other_latitude.cos() * (delta_longitude / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+    }
 }
@@ -0,0 +1,68 @@
+use crate::models::{Coordinates, Location};
+use serde::{Deserialize, Serialize};
+
+/// A Yelp event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    /// Event id.
+    pub id: String,
+
+    /// Name of this event.
+    pub name: String,
+
+    /// Description excerpt of this event.
+    pub description: Option<String>,
+
+    /// The category of this event.
+    pub category: Option<String>,
+
+    /// Number of Yelp users attending this event.
+    pub attending_count: usize,
+
+    /// Number of Yelp users interested in attending this event.
+    pub interested_count: usize,
+
+    /// Cost of attending this event.
+    pub cost: Option<f32>,
+
+    /// Maximum cost of this event.
+    pub cost_max: Option<f32>,
+
+    /// Whether this event is free.
+    pub is_free: bool,
+
+    /// Whether this event is canceled.
+    pub is_canceled: bool,
+
+    /// Whether this event is created by a Yelp community manager.
+    pub is_official: bool,
+
+    /// Yelp page of this event.
+    pub event_site_url: String,
+
+    /// URL to buy tickets for this event.
+    pub tickets_url: Option<String>,
+
+    /// Yelp image URL of this event.
+    pub image_url: Option<String>,
+
+    /// Time this event starts.
+    ///
+    /// ISO 8601 format with timezone offset, e.g. "2017-01-31T18:00:00-08:00".
+    pub time_start: String,
+
+    /// Time this event ends.
+    ///
+    /// ISO 8601 format with timezone offset, e.g. "2017-01-31T21:00:00-08:00".
+    pub time_end: Option<String>,
+
+    /// Coordinates of this event.
+    #[serde(flatten)]
+    pub coordinates: Coordinates,
+
+    /// Location of this event, including address, city, state, zip code and country.
+    pub location: Location,
+
+    /// Yelp ID of the business where this event takes place, if any.
+    pub business_id: Option<String>,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Sort order of the events returned by Event Search.
+///
+/// The default is `desc`.
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSortBy {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl EventSortBy {
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Self::Asc => "asc",
+            Self::Desc => "desc",
+        }
+    }
+}
+
+impl Display for EventSortBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Field the events returned by Event Search are sorted on.
+///
+/// The default is `popularity`.
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSortOn {
+    #[default]
+    Popularity,
+    TimeStart,
+}
+
+impl EventSortOn {
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Self::Popularity => "popularity",
+            Self::TimeStart => "time_start",
+        }
+    }
+}
+
+impl Display for EventSortOn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
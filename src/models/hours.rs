@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Opening hours of a business.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hours {
+    /// Opening hours of the business for each day of the week.
+    pub open: Vec<OpenHours>,
+
+    /// The type of the opening hours information.
+    ///
+    /// Right now, this is always `REGULAR`.
+    pub hours_type: String,
+
+    /// Whether the business is currently open or not.
+    pub is_open_now: bool,
+}
+
+/// A single opening window of a business.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenHours {
+    /// From 0 to 6, representing day of the week from Monday to Sunday.
+    ///
+    /// Notice that you may get the same day of the week more than once if the business has more
+    /// than one opening time slot.
+    pub day: u8,
+
+    /// Start of the opening hours in a day, in 24-hour clock notation.
+    ///
+    /// For example, 1000 means 10 AM.
+    pub start: String,
+
+    /// End of the opening hours in a day, in 24-hour clock notation.
+    ///
+    /// For example, 2130 means 9:30 PM.
+    pub end: String,
+
+    /// Whether the business opens overnight or not.
+    ///
+    /// When this is true, the end time will be lower than the start time.
+    pub is_overnight: bool,
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Specifies whether a match quality threshold should be applied to the matched businesses.
+///
+/// The default is `default`.
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchThreshold {
+    /// Do not apply any match quality threshold; all potential matches are returned.
+    None,
+
+    /// Apply a match quality threshold appropriate for most use cases.
+    #[default]
+    Default,
+
+    /// Apply a very strict match quality threshold.
+    Strict,
+}
+
+impl MatchThreshold {
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Self::None => "none",
+            Self::Default => "default",
+            Self::Strict => "strict",
+        }
+    }
+}
+
+impl Display for MatchThreshold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
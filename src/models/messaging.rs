@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Messaging information of a business.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Messaging {
+    /// Visit this URL to message the business.
+    pub url: String,
+
+    /// Indicates what kind of messaging can be done with this business.
+    ///
+    /// Example: "Request a Quote".
+    pub use_case_text: String,
+
+    /// How often the business responds to messages.
+    pub response_rate: Option<String>,
+
+    /// How quickly the business typically responds to messages.
+    pub response_time: Option<String>,
+
+    /// Whether the business is enrolled in Yelp's Request a Quote program.
+    pub is_enabled: Option<bool>,
+}
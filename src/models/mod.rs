@@ -1,19 +1,51 @@
 mod attribute;
+mod autocomplete_business;
+mod autocomplete_term;
+mod bounding_box;
 mod business;
+mod business_match;
 mod category;
+mod category_tree;
 mod coordinates;
+mod event;
+mod event_sort_by;
+mod event_sort_on;
+mod hours;
 mod location;
+mod match_threshold;
+mod messaging;
 mod price_type;
 mod region;
+mod review;
+mod review_sort_by;
+mod review_user;
+mod search_area;
 mod sort_by;
+mod special_hours;
 mod transaction_type;
 
 pub use attribute::*;
+pub use autocomplete_business::*;
+pub use autocomplete_term::*;
+pub use bounding_box::*;
 pub use business::*;
+pub use business_match::*;
 pub use category::*;
+pub use category_tree::*;
 pub use coordinates::*;
+pub use event::*;
+pub use event_sort_by::*;
+pub use event_sort_on::*;
+pub use hours::*;
 pub use location::*;
+pub use match_threshold::*;
+pub use messaging::*;
 pub use price_type::*;
 pub use region::*;
+pub use review::*;
+pub use review_sort_by::*;
+pub use review_user::*;
+pub use search_area::*;
 pub use sort_by::*;
+pub use special_hours::*;
 pub use transaction_type::*;
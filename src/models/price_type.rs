@@ -18,6 +18,14 @@ pub enum PriceType {
 }
 
 impl PriceType {
+    /// Every variant, in declaration order.
+    pub const ALL: [Self; 4] = [
+        Self::OneDollar,
+        Self::TwoDollar,
+        Self::ThreeDollar,
+        Self::FourDollar,
+    ];
+
     #[must_use]
     pub const fn as_str(&self) -> &str {
         match self {
@@ -0,0 +1,26 @@
+use crate::models::ReviewUser;
+use serde::{Deserialize, Serialize};
+
+/// A review excerpt for a business.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Review {
+    /// A unique identifier for this review.
+    pub id: String,
+
+    /// URL of this review.
+    pub url: String,
+
+    /// Text excerpt of this review.
+    pub text: String,
+
+    /// Rating of this review.
+    pub rating: f32,
+
+    /// The time that the review was created in PST.
+    ///
+    /// Example: "2016-08-29 00:41:13".
+    pub time_created: String,
+
+    /// The user who wrote the review.
+    pub user: ReviewUser,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Sort mode for the reviews of a business.
+///
+/// The default is `yelp_sort`.
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewSortBy {
+    #[default]
+    YelpSort,
+    Newest,
+}
+
+impl ReviewSortBy {
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Self::YelpSort => "yelp_sort",
+            Self::Newest => "newest",
+        }
+    }
+}
+
+impl Display for ReviewSortBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
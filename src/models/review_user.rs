@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// The user who wrote a review.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewUser {
+    /// ID of the user.
+    pub id: String,
+
+    /// URL of the user's profile.
+    pub profile_url: String,
+
+    /// URL of the user's profile photo.
+    pub image_url: Option<String>,
+
+    /// Name of the user.
+    pub name: String,
+}
@@ -0,0 +1,36 @@
+use crate::models::{BoundingBox, Coordinates};
+use serde::{Deserialize, Serialize};
+
+/// Area covered by a tiled Business Search.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub enum SearchArea {
+    BoundingBox(BoundingBox),
+
+    /// Circle of `radius` meters around `center`.
+    ///
+    /// Unlike `BusinessSearchPayload::radius`, `radius` may be over 40,000 meters.
+    Circle {
+        center: Coordinates,
+        radius: usize,
+    },
+}
+
+impl SearchArea {
+    /// Smallest box containing the whole area.
+    #[must_use]
+    pub fn bounding_box(&self) -> BoundingBox {
+        match self {
+            Self::BoundingBox(bounding_box) => *bounding_box,
+            Self::Circle { center, radius } => BoundingBox::around(*center, *radius),
+        }
+    }
+
+    #[must_use]
+    #[expect(clippy::cast_precision_loss)]
+    pub fn contains(&self, coordinates: &Coordinates) -> bool {
+        match self {
+            Self::BoundingBox(bounding_box) => bounding_box.contains(coordinates),
+            Self::Circle { center, radius } => center.distance_to(coordinates) <= *radius as f64,
+        }
+    }
+}
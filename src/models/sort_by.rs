@@ -10,22 +10,25 @@ use std::fmt::{Display, Formatter};
 /// For example, the rating sort is not strictly sorted by the rating value, but by an adjusted
 /// rating value that takes into account the number of ratings, similar to a Bayesian average.
 /// This is to prevent skewing results to businesses with a single review.
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SortBy {
+    #[default]
     BestMatch,
     Rating,
     ReviewCount,
     Distance,
 }
 
-impl Default for SortBy {
-    fn default() -> Self {
-        Self::BestMatch
-    }
-}
-
 impl SortBy {
+    /// Every variant, in declaration order.
+    pub const ALL: [Self; 4] = [
+        Self::BestMatch,
+        Self::Rating,
+        Self::ReviewCount,
+        Self::Distance,
+    ];
+
     #[must_use]
     pub const fn as_str(&self) -> &str {
         match self {
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Out of the ordinary hours for a business that apply on certain dates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecialHours {
+    /// An ISO8601 date string representing the date for which these special hours apply.
+    pub date: String,
+
+    /// Whether this particular special hour represents a date where the business is closed.
+    pub is_closed: Option<bool>,
+
+    /// Start of the opening hours in a day, in 24-hour clock notation.
+    pub start: Option<String>,
+
+    /// End of the opening hours in a day, in 24-hour clock notation.
+    pub end: Option<String>,
+
+    /// Whether the special hours time range spans across midnight or not.
+    ///
+    /// When this is true, the end time will be lower than the start time.
+    pub is_overnight: Option<bool>,
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 /// Transactions that the business is registered for.
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
@@ -13,3 +14,20 @@ pub enum TransactionType {
     /// Able to make reservations.
     RestaurantReservation,
 }
+
+impl TransactionType {
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Self::Pickup => "pickup",
+            Self::Delivery => "delivery",
+            Self::RestaurantReservation => "restaurant_reservation",
+        }
+    }
+}
+
+impl Display for TransactionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,132 @@
+use crate::error::Error;
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Client-side token bucket limiting the requests per second, and optionally per day, sent by a
+/// client.
+///
+/// Clones share the same budget, so a limiter can be shared between clones of a client, or even
+/// between several clients using the same API key.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    state: Arc<Mutex<RateLimiterState>>,
+}
+
+#[derive(Debug)]
+struct RateLimiterState {
+    /// Tokens added to the bucket per second.
+    per_second: f64,
+
+    /// Maximum number of tokens in the bucket.
+    burst: f64,
+
+    /// Tokens currently in the bucket; negative when requests are queued.
+    tokens: f64,
+
+    /// When `tokens` was last refilled.
+    last_refill: Instant,
+
+    /// Maximum number of requests per UTC day.
+    daily_quota: Option<u64>,
+
+    /// Number of requests sent during `day`.
+    daily_used: u64,
+
+    /// The current UTC day, as days since the Unix epoch.
+    day: u64,
+}
+
+impl RateLimiter {
+    /// Allows `per_second` requests per second, with bursts of up to `per_second` requests.
+    #[must_use]
+    pub fn new(per_second: NonZeroU32) -> Self {
+        let per_second: u32 = per_second.get();
+        Self {
+            state: Arc::new(Mutex::new(RateLimiterState {
+                per_second: f64::from(per_second),
+                burst: f64::from(per_second),
+                tokens: f64::from(per_second),
+                last_refill: Instant::now(),
+                daily_quota: None,
+                daily_used: 0,
+                day: current_day(),
+            })),
+        }
+    }
+
+    /// Additionally allows at most `daily_quota` requests per UTC day (Yelp's quota resets at
+    /// midnight UTC).
+    #[must_use]
+    pub fn with_daily_quota(self, daily_quota: u64) -> Self {
+        self.lock().daily_quota = Some(daily_quota);
+        self
+    }
+
+    /// Number of requests that may still be sent today, if a daily quota is configured.
+    #[must_use]
+    pub fn daily_remaining(&self) -> Option<u64> {
+        let mut state = self.lock();
+        state.roll_over_day();
+        state
+            .daily_quota
+            .map(|daily_quota| daily_quota.saturating_sub(state.daily_used))
+    }
+
+    /// Reserves the budget for one request, returning how long the caller has to wait before
+    /// sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::LocalQuotaExhausted` if the daily quota has been used up.
+    pub fn acquire(&self) -> Result<Duration, Error> {
+        let mut state = self.lock();
+
+        // check the daily budget
+        state.roll_over_day();
+        if let Some(daily_quota) = state.daily_quota {
+            if state.daily_used >= daily_quota {
+                return Err(Error::LocalQuotaExhausted { daily_quota });
+            }
+        }
+        state.daily_used += 1;
+
+        // refill the bucket, then take a token (possibly going into debt)
+        let now: Instant = Instant::now();
+        let elapsed: f64 = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = state
+            .burst
+            .min(elapsed.mul_add(state.per_second, state.tokens));
+        state.last_refill = now;
+        state.tokens -= 1.0;
+
+        if state.tokens >= 0.0 {
+            return Ok(Duration::ZERO);
+        }
+        Ok(Duration::from_secs_f64(-state.tokens / state.per_second))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, RateLimiterState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl RateLimiterState {
+    fn roll_over_day(&mut self) {
+        let day: u64 = current_day();
+        if day != self.day {
+            self.day = day;
+            self.daily_used = 0;
+        }
+    }
+}
+
+fn current_day() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        / SECONDS_PER_DAY
+}
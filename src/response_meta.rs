@@ -0,0 +1,153 @@
+use http::StatusCode;
+use http::header::HeaderMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const DAILY_LIMIT_HEADER: &str = "ratelimit-dailylimit";
+const REMAINING_HEADER: &str = "ratelimit-remaining";
+const RESET_TIME_HEADER: &str = "ratelimit-resettime";
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Information about a response, besides its body.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// Status code of the response.
+    pub status_code: StatusCode,
+
+    /// Yelp's identifier of the request, useful when contacting Yelp support.
+    pub request_id: Option<String>,
+
+    /// Rate limit information sent along with the response.
+    pub rate_limit: Option<RateLimit>,
+
+    /// Time between sending the request and receiving the whole response body.
+    ///
+    /// Only covers the last attempt when the request was retried.
+    pub latency: Duration,
+
+    /// Whether the response was served from the client's `ResponseCache` instead of being
+    /// requested from Yelp.
+    pub cache_hit: bool,
+}
+
+impl ResponseMeta {
+    pub(crate) fn new(status_code: StatusCode, headers: &HeaderMap, latency: Duration) -> Self {
+        Self {
+            status_code,
+            request_id: header_str(headers, REQUEST_ID_HEADER).map(ToString::to_string),
+            rate_limit: RateLimit::from_headers(headers),
+            latency,
+            cache_hit: false,
+        }
+    }
+
+    /// Meta of a response served from the client's `ResponseCache`.
+    pub(crate) const fn cached(latency: Duration) -> Self {
+        Self {
+            status_code: StatusCode::OK,
+            request_id: None,
+            rate_limit: None,
+            latency,
+            cache_hit: true,
+        }
+    }
+}
+
+/// Daily API quota, as reported by Yelp's `RateLimit-*` response headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Number of requests the API key may send per day (`RateLimit-DailyLimit`).
+    pub daily_limit: Option<u64>,
+
+    /// Number of requests the API key may still send today (`RateLimit-Remaining`).
+    pub remaining: Option<u64>,
+
+    /// When the daily quota resets, as an ISO 8601 timestamp (`RateLimit-ResetTime`).
+    pub reset_time: Option<String>,
+}
+
+impl RateLimit {
+    /// Parses the `RateLimit-*` headers, returning `None` if none of them are present.
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let rate_limit: Self = Self {
+            daily_limit: header_str(headers, DAILY_LIMIT_HEADER).and_then(|v| v.parse().ok()),
+            remaining: header_str(headers, REMAINING_HEADER).and_then(|v| v.parse().ok()),
+            reset_time: header_str(headers, RESET_TIME_HEADER).map(ToString::to_string),
+        };
+        (rate_limit.daily_limit.is_some()
+            || rate_limit.remaining.is_some()
+            || rate_limit.reset_time.is_some())
+        .then_some(rate_limit)
+    }
+
+    /// When the daily quota resets, parsed from `reset_time`.
+    ///
+    /// Returns `None` if `reset_time` is not set, or is not a valid ISO 8601 timestamp.
+    #[must_use]
+    pub fn reset_at(&self) -> Option<SystemTime> {
+        parse_timestamp(self.reset_time.as_deref()?)
+    }
+}
+
+/// Parses an ISO 8601 timestamp such as `2024-01-31T00:00:00+00:00`, as sent by Yelp.
+fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+    let (date, time) = timestamp.split_once(['T', ' '])?;
+
+    let mut date_parts = date.splitn(3, '-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: i64 = date_parts.next()?.parse().ok()?;
+    let day: i64 = date_parts.next()?.parse().ok()?;
+
+    let (time, offset): (&str, i64) = if let Some(time) = time.strip_suffix('Z') {
+        (time, 0)
+    } else if let Some(index) = time.rfind(['+', '-']) {
+        let (time, offset) = time.split_at(index);
+        let sign: i64 = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset[1..].split_once(':').unwrap_or((&offset[1..], "0"));
+        let offset: i64 = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+        (time, sign * offset)
+    } else {
+        (time, 0)
+    };
+
+    let mut time_parts = time.splitn(3, ':');
+    let hour: i64 = time_parts.next()?.parse().ok()?;
+    let minute: i64 = time_parts.next()?.parse().ok()?;
+    // fractional seconds are dropped
+    let second: i64 = time_parts
+        .next()
+        .map_or(Ok(0), |second| {
+            second.split('.').next().unwrap_or_default().parse()
+        })
+        .ok()?;
+
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || !(0..24).contains(&hour)
+        || !(0..60).contains(&minute)
+        || !(0..=60).contains(&second)
+    {
+        return None;
+    }
+
+    let seconds: i64 =
+        days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second - offset;
+    u64::try_from(seconds)
+        .ok()
+        .map(|seconds| UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+/// Number of days between 1970-01-01 and the given date of the proleptic Gregorian calendar.
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year: i64 = if month <= 2 { year - 1 } else { year };
+    let era: i64 = year.div_euclid(400);
+    let year_of_era: i64 = year - era * 400;
+    // months counted from March, so that the leap day is the last day of the year
+    let day_of_year: i64 = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name)?.to_str().ok().map(str::trim)
+}
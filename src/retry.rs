@@ -0,0 +1,106 @@
+use http::header::{HeaderMap, RETRY_AFTER};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Controls whether and how failed requests are retried.
+///
+/// A request is retried when `Error::is_retryable` returns true for its error, e.g. for
+/// `TOO_MANY_REQUESTS_PER_SECOND`, `INTERNAL_ERROR`, `SERVICE_UNAVAILABLE`, `429`/`502`/`503`/`504`
+/// responses without a known error code, and connection errors; see `Error::is_retryable` for the
+/// full list. Authentication (e.g. `TOKEN_INVALID`), quota and validation errors are never retried.
+///
+/// The delay before retry `n` is `base_delay * 2^(n - 1)`, capped at `max_delay`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one.
+    ///
+    /// `1` disables retries.
+    pub max_attempts: u32,
+
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+
+    /// Upper bound for the delay between two attempts.
+    pub max_delay: Duration,
+
+    /// When true, each delay is randomized between zero and its computed value ("full jitter"),
+    /// so that many clients don't retry in lockstep.
+    pub jitter: bool,
+
+    /// When true, a `Retry-After` header sent by Yelp is used as the delay instead of the
+    /// computed backoff.
+    ///
+    /// If it asks to wait longer than `max_delay`, the request is not retried.
+    pub respect_retry_after: bool,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            jitter: false,
+            respect_retry_after: false,
+        }
+    }
+
+    /// Returns how long to wait before retrying a request that failed on attempt number
+    /// `attempt` (starting at `1`), or `None` if it should not be retried anymore.
+    ///
+    /// `retry_after` is the delay requested by the server's `Retry-After` header, if any.
+    #[must_use]
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        // the server knows best
+        if let (true, Some(retry_after)) = (self.respect_retry_after, retry_after) {
+            return (retry_after <= self.max_delay).then_some(retry_after);
+        }
+
+        // exponential backoff
+        let backoff: Duration = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt - 1))
+            .min(self.max_delay);
+        if !self.jitter {
+            return Some(backoff);
+        }
+
+        // full jitter
+        let random: u64 = RandomState::new().build_hasher().finish();
+        let nanos: u64 = u64::try_from(backoff.as_nanos()).unwrap_or(u64::MAX);
+        Some(Duration::from_nanos(random % nanos.saturating_add(1)))
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Up to `3` attempts, starting at `500ms` and waiting at most `30s` between attempts, with
+    /// jitter and `Retry-After` support.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            respect_retry_after: true,
+        }
+    }
+}
+
+/// Parses the `Retry-After` header, when it is given in seconds.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
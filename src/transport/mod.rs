@@ -0,0 +1,61 @@
+#[cfg(feature = "reqwest")]
+mod reqwest_transport;
+
+#[cfg(feature = "reqwest")]
+pub use reqwest_transport::*;
+
+use crate::error::Error;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http::header::HeaderMap;
+use http::{Method, StatusCode};
+use std::fmt::Debug;
+use url::Url;
+
+/// HTTP request sent by a client through its `Transport`.
+///
+/// The request is complete: the query params are part of `url`, and `headers` include the
+/// `Authorization` header.
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Bytes>,
+}
+
+/// HTTP response returned by a `Transport`, whatever its status code.
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    pub status_code: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+/// Sends the HTTP requests of a `YelpFusionClient`.
+///
+/// `ReqwestTransport` is used by default, with the default `reqwest` feature. Implement this
+/// trait to send requests with another HTTP client, or to return canned responses in tests.
+pub trait Transport: Debug + Send + Sync {
+    /// Sends `request` and reads the whole response body.
+    ///
+    /// # Errors
+    ///
+    /// Should return `Error::TransportError` if no response was received, e.g. because the
+    /// connection failed. Responses with a failed status code must be returned as `Ok`.
+    fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse, Error>>;
+}
+
+/// Sends the HTTP requests of a `BlockingYelpFusionClient`.
+///
+/// `BlockingReqwestTransport` is used by default, with the default `reqwest` feature. Implement
+/// this trait to send requests with another HTTP client, or to return canned responses in tests.
+pub trait BlockingTransport: Debug + Send + Sync {
+    /// Sends `request` and reads the whole response body.
+    ///
+    /// # Errors
+    ///
+    /// Should return `Error::TransportError` if no response was received, e.g. because the
+    /// connection failed. Responses with a failed status code must be returned as `Ok`.
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error>;
+}
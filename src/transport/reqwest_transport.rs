@@ -0,0 +1,79 @@
+use crate::error::Error;
+use crate::transport::{BlockingTransport, Transport, TransportRequest, TransportResponse};
+use futures::future::BoxFuture;
+use reqwest::Client;
+use reqwest::blocking::Client as BlockingClient;
+
+/// `Transport` sending requests with a `reqwest::Client`.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    #[must_use]
+    pub const fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse, Error>> {
+        Box::pin(async move {
+            let mut request_builder = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                request_builder = request_builder.body(body);
+            }
+
+            let response = request_builder.send().await.map_err(transport_error)?;
+            Ok(TransportResponse {
+                status_code: response.status(),
+                headers: response.headers().clone(),
+                body: response.bytes().await.map_err(transport_error)?,
+            })
+        })
+    }
+}
+
+/// `BlockingTransport` sending requests with a `reqwest::blocking::Client`.
+#[derive(Debug, Clone, Default)]
+pub struct BlockingReqwestTransport {
+    client: BlockingClient,
+}
+
+impl BlockingReqwestTransport {
+    #[must_use]
+    pub const fn new(client: BlockingClient) -> Self {
+        Self { client }
+    }
+}
+
+impl BlockingTransport for BlockingReqwestTransport {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        let mut request_builder = self
+            .client
+            .request(request.method, request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            request_builder = request_builder.body(body);
+        }
+
+        let response = request_builder.send().map_err(transport_error)?;
+        Ok(TransportResponse {
+            status_code: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes().map_err(transport_error)?,
+        })
+    }
+}
+
+/// Wraps a `reqwest` error, which is retryable if it happened while connecting or timed out.
+pub(crate) fn transport_error(e: reqwest::Error) -> Error {
+    Error::TransportError {
+        retryable: e.is_connect() || e.is_timeout(),
+        error: Box::new(e),
+    }
+}
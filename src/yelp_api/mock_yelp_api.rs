@@ -0,0 +1,564 @@
+use crate::endpoints::{
+    AllCategoriesResponse, AutocompleteResponse, BusinessDetailsResponse, BusinessMatchPayload,
+    BusinessMatchResponse, BusinessReviewsPayload, BusinessReviewsResponse, BusinessSearchPayload,
+    BusinessSearchResponse, CategoryDetailsResponse, Endpoint, EventSearchPayload,
+    EventSearchResponse, FeaturedEventPayload, PhoneSearchPayload, PhoneSearchResponse,
+    TransactionSearchPayload, TransactionSearchResponse,
+};
+use crate::error::Error;
+use crate::graphql::{GraphQlBusiness, GraphQlRequest, GraphQlReviews, GraphQlSearch};
+use crate::models::{Coordinates, Event};
+use crate::response_meta::ResponseMeta;
+use crate::yelp_api::{BlockingYelpApi, YelpApi, YelpApiCall};
+use futures::future::{self, BoxFuture};
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// `YelpApi` and `BlockingYelpApi` returning canned responses, and recording every call.
+///
+/// Responses are queued per method with the typed `push_*` methods, and returned in the order
+/// they were pushed. Clones share their responses and calls, so a clone can be handed to the
+/// code under test while the original is used for assertions.
+///
+/// Calling a method panics if no response is left for it.
+#[derive(Debug, Clone, Default)]
+pub struct MockYelpApi {
+    state: Arc<Mutex<MockYelpApiState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockYelpApiState {
+    /// Queued responses of each method, each a `Result` of the method's response type.
+    responses: HashMap<&'static str, VecDeque<Box<dyn Any + Send>>>,
+    calls: Vec<YelpApiCall>,
+    response_meta: Option<ResponseMeta>,
+}
+
+impl MockYelpApi {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues `response` as the result of a `business_search` call, with or without meta.
+    pub fn push_business_search(&self, response: Result<BusinessSearchResponse, Error>) {
+        self.push("business_search", response);
+    }
+
+    /// Queues `response` as the result of a `business_details` call, with or without meta.
+    pub fn push_business_details(&self, response: Result<BusinessDetailsResponse, Error>) {
+        self.push("business_details", response);
+    }
+
+    /// Queues `response` as the result of a `business_reviews` call, with or without meta.
+    pub fn push_business_reviews(&self, response: Result<BusinessReviewsResponse, Error>) {
+        self.push("business_reviews", response);
+    }
+
+    /// Queues `response` as the result of a `business_match` call, with or without meta.
+    pub fn push_business_match(&self, response: Result<BusinessMatchResponse, Error>) {
+        self.push("business_match", response);
+    }
+
+    /// Queues `response` as the result of a `phone_search` call, with or without meta.
+    pub fn push_phone_search(&self, response: Result<PhoneSearchResponse, Error>) {
+        self.push("phone_search", response);
+    }
+
+    /// Queues `response` as the result of a `transaction_search` call, with or without meta.
+    pub fn push_transaction_search(&self, response: Result<TransactionSearchResponse, Error>) {
+        self.push("transaction_search", response);
+    }
+
+    /// Queues `response` as the result of a `autocomplete` call, with or without meta.
+    pub fn push_autocomplete(&self, response: Result<AutocompleteResponse, Error>) {
+        self.push("autocomplete", response);
+    }
+
+    /// Queues `response` as the result of a `all_categories` call, with or without meta.
+    pub fn push_all_categories(&self, response: Result<AllCategoriesResponse, Error>) {
+        self.push("all_categories", response);
+    }
+
+    /// Queues `response` as the result of a `category_details` call, with or without meta.
+    pub fn push_category_details(&self, response: Result<CategoryDetailsResponse, Error>) {
+        self.push("category_details", response);
+    }
+
+    /// Queues `response` as the result of a `event_search` call, with or without meta.
+    pub fn push_event_search(&self, response: Result<EventSearchResponse, Error>) {
+        self.push("event_search", response);
+    }
+
+    /// Queues `response` as the result of a `event_lookup` call, with or without meta.
+    pub fn push_event_lookup(&self, response: Result<Event, Error>) {
+        self.push("event_lookup", response);
+    }
+
+    /// Queues `response` as the result of a `featured_event` call, with or without meta.
+    pub fn push_featured_event(&self, response: Result<Event, Error>) {
+        self.push("featured_event", response);
+    }
+
+    /// Queues `response` as the `data` of a call to `graphql`, deserialized into the type the
+    /// call asks for.
+    pub fn push_graphql(&self, response: Result<serde_json::Value, Error>) {
+        self.push("graphql", response);
+    }
+
+    /// Queues `response` as the result of a call to `graphql_business`.
+    pub fn push_graphql_business(&self, response: Result<GraphQlBusiness, Error>) {
+        self.push("graphql_business", response);
+    }
+
+    /// Queues `response` as the result of a call to `graphql_businesses`.
+    pub fn push_graphql_businesses(&self, response: Result<Vec<Option<GraphQlBusiness>>, Error>) {
+        self.push("graphql_businesses", response);
+    }
+
+    /// Queues `response` as the result of a call to `graphql_search`.
+    pub fn push_graphql_search(&self, response: Result<GraphQlSearch, Error>) {
+        self.push("graphql_search", response);
+    }
+
+    /// Queues `response` as the result of a call to `graphql_reviews`.
+    pub fn push_graphql_reviews(&self, response: Result<GraphQlReviews, Error>) {
+        self.push("graphql_reviews", response);
+    }
+
+    /// Returns `response_meta` along with the successful responses of the `_with_meta` methods.
+    ///
+    /// Defaults to the meta of a `200 OK` response without request ID or rate limit information.
+    pub fn set_response_meta(&self, response_meta: ResponseMeta) {
+        self.lock().response_meta = Some(response_meta);
+    }
+
+    /// Every call made so far, in order.
+    #[must_use]
+    pub fn calls(&self) -> Vec<YelpApiCall> {
+        self.lock().calls.clone()
+    }
+
+    /// Every call made so far to `endpoint`, in order.
+    #[must_use]
+    pub fn calls_to(&self, endpoint: Endpoint) -> Vec<YelpApiCall> {
+        self.lock()
+            .calls
+            .iter()
+            .filter(|call| call.endpoint() == Some(endpoint))
+            .cloned()
+            .collect()
+    }
+
+    /// Queues `response` as the result of a call to `method`, whose response type must be `T`.
+    fn push<T: Send + 'static>(&self, method: &'static str, response: Result<T, Error>) {
+        self.lock()
+            .responses
+            .entry(method)
+            .or_default()
+            .push_back(Box::new(response));
+    }
+
+    /// Records `call`, and returns the next response of its method.
+    fn respond<T: Send + 'static>(&self, call: YelpApiCall) -> Result<T, Error> {
+        let method: &'static str = call.method();
+        let mut state = self.lock();
+        state.calls.push(call);
+        let Some(response) = state
+            .responses
+            .get_mut(method)
+            .and_then(VecDeque::pop_front)
+        else {
+            panic!("no response left for {method}");
+        };
+        drop(state);
+
+        // the typed `push_*` methods only queue responses of the method's response type
+        *response
+            .downcast::<Result<T, Error>>()
+            .unwrap_or_else(|_| unreachable!("response for {method} has the wrong type"))
+    }
+
+    /// Same as `respond`, adding the `ResponseMeta` of successful responses.
+    fn respond_with_meta<T: Send + 'static>(
+        &self,
+        call: YelpApiCall,
+    ) -> Result<(T, ResponseMeta), Error> {
+        let response: T = self.respond(call)?;
+        let response_meta: ResponseMeta = self
+            .lock()
+            .response_meta
+            .clone()
+            .unwrap_or_else(default_response_meta);
+        Ok((response, response_meta))
+    }
+
+    /// Records a `graphql` call, and deserializes the next queued `data` into `T`.
+    fn respond_graphql<T: DeserializeOwned>(&self, request: &GraphQlRequest) -> Result<T, Error> {
+        let data: serde_json::Value = self.respond(YelpApiCall::GraphQl(request.clone()))?;
+        Ok(serde_json::from_value(data)?)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockYelpApiState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn default_response_meta() -> ResponseMeta {
+    ResponseMeta {
+        status_code: StatusCode::OK,
+        request_id: None,
+        rate_limit: None,
+        latency: Duration::ZERO,
+        cache_hit: false,
+    }
+}
+
+impl YelpApi for MockYelpApi {
+    fn business_search_with_meta<'a>(
+        &'a self,
+        payload: &'a BusinessSearchPayload,
+    ) -> BoxFuture<'a, Result<(BusinessSearchResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(
+            self.respond_with_meta(YelpApiCall::BusinessSearch(payload.clone())),
+        ))
+    }
+
+    fn business_details_with_meta<'a>(
+        &'a self,
+        id_or_alias: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(BusinessDetailsResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(self.respond_with_meta(
+            YelpApiCall::BusinessDetails {
+                id_or_alias: id_or_alias.to_string(),
+                locale,
+            },
+        )))
+    }
+
+    fn business_reviews_with_meta<'a>(
+        &'a self,
+        id_or_alias: &'a str,
+        payload: &'a BusinessReviewsPayload,
+    ) -> BoxFuture<'a, Result<(BusinessReviewsResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(self.respond_with_meta(
+            YelpApiCall::BusinessReviews {
+                id_or_alias: id_or_alias.to_string(),
+                payload: payload.clone(),
+            },
+        )))
+    }
+
+    fn business_match_with_meta<'a>(
+        &'a self,
+        payload: &'a BusinessMatchPayload,
+    ) -> BoxFuture<'a, Result<(BusinessMatchResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(
+            self.respond_with_meta(YelpApiCall::BusinessMatch(payload.clone())),
+        ))
+    }
+
+    fn phone_search_with_meta<'a>(
+        &'a self,
+        payload: &'a PhoneSearchPayload,
+    ) -> BoxFuture<'a, Result<(PhoneSearchResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(
+            self.respond_with_meta(YelpApiCall::PhoneSearch(payload.clone())),
+        ))
+    }
+
+    fn transaction_search_with_meta<'a>(
+        &'a self,
+        payload: &'a TransactionSearchPayload,
+    ) -> BoxFuture<'a, Result<(TransactionSearchResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(self.respond_with_meta(
+            YelpApiCall::TransactionSearch(payload.clone()),
+        )))
+    }
+
+    fn autocomplete_with_meta<'a>(
+        &'a self,
+        text: &'a str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(AutocompleteResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(self.respond_with_meta(
+            YelpApiCall::Autocomplete {
+                text: text.to_string(),
+                coordinates,
+                locale,
+            },
+        )))
+    }
+
+    fn all_categories_with_meta(
+        &self,
+        locale: Option<String>,
+    ) -> BoxFuture<'_, Result<(AllCategoriesResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(
+            self.respond_with_meta(YelpApiCall::AllCategories { locale }),
+        ))
+    }
+
+    fn category_details_with_meta<'a>(
+        &'a self,
+        alias: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(CategoryDetailsResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(self.respond_with_meta(
+            YelpApiCall::CategoryDetails {
+                alias: alias.to_string(),
+                locale,
+            },
+        )))
+    }
+
+    fn event_search_with_meta<'a>(
+        &'a self,
+        payload: &'a EventSearchPayload,
+    ) -> BoxFuture<'a, Result<(EventSearchResponse, ResponseMeta), Error>> {
+        Box::pin(future::ready(
+            self.respond_with_meta(YelpApiCall::EventSearch(payload.clone())),
+        ))
+    }
+
+    fn event_lookup_with_meta<'a>(
+        &'a self,
+        id: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(Event, ResponseMeta), Error>> {
+        Box::pin(future::ready(self.respond_with_meta(
+            YelpApiCall::EventLookup {
+                id: id.to_string(),
+                locale,
+            },
+        )))
+    }
+
+    fn featured_event_with_meta<'a>(
+        &'a self,
+        payload: &'a FeaturedEventPayload,
+    ) -> BoxFuture<'a, Result<(Event, ResponseMeta), Error>> {
+        Box::pin(future::ready(
+            self.respond_with_meta(YelpApiCall::FeaturedEvent(payload.clone())),
+        ))
+    }
+
+    fn graphql<'a, T: DeserializeOwned + Send + 'a>(
+        &'a self,
+        request: &'a GraphQlRequest,
+    ) -> BoxFuture<'a, Result<T, Error>> {
+        Box::pin(future::ready(self.respond_graphql(request)))
+    }
+
+    fn graphql_business<'a>(
+        &'a self,
+        id: &'a str,
+        review_limit: Option<usize>,
+    ) -> BoxFuture<'a, Result<GraphQlBusiness, Error>> {
+        Box::pin(future::ready(self.respond(YelpApiCall::GraphQlBusiness {
+            id: id.to_string(),
+            review_limit,
+        })))
+    }
+
+    fn graphql_businesses<'a>(
+        &'a self,
+        ids: &'a [String],
+        review_limit: Option<usize>,
+    ) -> BoxFuture<'a, Result<Vec<Option<GraphQlBusiness>>, Error>> {
+        Box::pin(future::ready(self.respond(
+            YelpApiCall::GraphQlBusinesses {
+                ids: ids.to_vec(),
+                review_limit,
+            },
+        )))
+    }
+
+    fn graphql_search<'a>(
+        &'a self,
+        payload: &'a BusinessSearchPayload,
+        review_limit: Option<usize>,
+    ) -> BoxFuture<'a, Result<GraphQlSearch, Error>> {
+        Box::pin(future::ready(self.respond(YelpApiCall::GraphQlSearch {
+            payload: payload.clone(),
+            review_limit,
+        })))
+    }
+
+    fn graphql_reviews<'a>(
+        &'a self,
+        id: &'a str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> BoxFuture<'a, Result<GraphQlReviews, Error>> {
+        Box::pin(future::ready(self.respond(YelpApiCall::GraphQlReviews {
+            id: id.to_string(),
+            limit,
+            offset,
+        })))
+    }
+}
+
+impl BlockingYelpApi for MockYelpApi {
+    fn business_search_with_meta(
+        &self,
+        payload: &BusinessSearchPayload,
+    ) -> Result<(BusinessSearchResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::BusinessSearch(payload.clone()))
+    }
+
+    fn business_details_with_meta(
+        &self,
+        id_or_alias: &str,
+        locale: Option<String>,
+    ) -> Result<(BusinessDetailsResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::BusinessDetails {
+            id_or_alias: id_or_alias.to_string(),
+            locale,
+        })
+    }
+
+    fn business_reviews_with_meta(
+        &self,
+        id_or_alias: &str,
+        payload: &BusinessReviewsPayload,
+    ) -> Result<(BusinessReviewsResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::BusinessReviews {
+            id_or_alias: id_or_alias.to_string(),
+            payload: payload.clone(),
+        })
+    }
+
+    fn business_match_with_meta(
+        &self,
+        payload: &BusinessMatchPayload,
+    ) -> Result<(BusinessMatchResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::BusinessMatch(payload.clone()))
+    }
+
+    fn phone_search_with_meta(
+        &self,
+        payload: &PhoneSearchPayload,
+    ) -> Result<(PhoneSearchResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::PhoneSearch(payload.clone()))
+    }
+
+    fn transaction_search_with_meta(
+        &self,
+        payload: &TransactionSearchPayload,
+    ) -> Result<(TransactionSearchResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::TransactionSearch(payload.clone()))
+    }
+
+    fn autocomplete_with_meta(
+        &self,
+        text: &str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> Result<(AutocompleteResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::Autocomplete {
+            text: text.to_string(),
+            coordinates,
+            locale,
+        })
+    }
+
+    fn all_categories_with_meta(
+        &self,
+        locale: Option<String>,
+    ) -> Result<(AllCategoriesResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::AllCategories { locale })
+    }
+
+    fn category_details_with_meta(
+        &self,
+        alias: &str,
+        locale: Option<String>,
+    ) -> Result<(CategoryDetailsResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::CategoryDetails {
+            alias: alias.to_string(),
+            locale,
+        })
+    }
+
+    fn event_search_with_meta(
+        &self,
+        payload: &EventSearchPayload,
+    ) -> Result<(EventSearchResponse, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::EventSearch(payload.clone()))
+    }
+
+    fn event_lookup_with_meta(
+        &self,
+        id: &str,
+        locale: Option<String>,
+    ) -> Result<(Event, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::EventLookup {
+            id: id.to_string(),
+            locale,
+        })
+    }
+
+    fn featured_event_with_meta(
+        &self,
+        payload: &FeaturedEventPayload,
+    ) -> Result<(Event, ResponseMeta), Error> {
+        self.respond_with_meta(YelpApiCall::FeaturedEvent(payload.clone()))
+    }
+
+    fn graphql<T: DeserializeOwned>(&self, request: &GraphQlRequest) -> Result<T, Error> {
+        self.respond_graphql(request)
+    }
+
+    fn graphql_business(
+        &self,
+        id: &str,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlBusiness, Error> {
+        self.respond(YelpApiCall::GraphQlBusiness {
+            id: id.to_string(),
+            review_limit,
+        })
+    }
+
+    fn graphql_businesses(
+        &self,
+        ids: &[String],
+        review_limit: Option<usize>,
+    ) -> Result<Vec<Option<GraphQlBusiness>>, Error> {
+        self.respond(YelpApiCall::GraphQlBusinesses {
+            ids: ids.to_vec(),
+            review_limit,
+        })
+    }
+
+    fn graphql_search(
+        &self,
+        payload: &BusinessSearchPayload,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlSearch, Error> {
+        self.respond(YelpApiCall::GraphQlSearch {
+            payload: payload.clone(),
+            review_limit,
+        })
+    }
+
+    fn graphql_reviews(
+        &self,
+        id: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<GraphQlReviews, Error> {
+        self.respond(YelpApiCall::GraphQlReviews {
+            id: id.to_string(),
+            limit,
+            offset,
+        })
+    }
+}
@@ -0,0 +1,1051 @@
+//! Traits abstracting the endpoints of the Yelp Fusion API, so that code depending on a client
+//! can be tested without network.
+//!
+//! `YelpFusionClient` implements `YelpApi`, and `BlockingYelpFusionClient` implements
+//! `BlockingYelpApi`. `MockYelpApi` implements both, returning canned responses and recording
+//! every call.
+
+mod mock_yelp_api;
+mod yelp_api_call;
+
+use crate::endpoints::{
+    AllCategoriesResponse, AutocompleteResponse, BusinessDetailsResponse, BusinessMatchPayload,
+    BusinessMatchResponse, BusinessReviewsPayload, BusinessReviewsResponse, BusinessSearchPayload,
+    BusinessSearchResponse, CategoryDetailsResponse, EventSearchPayload, EventSearchResponse,
+    FeaturedEventPayload, PhoneSearchPayload, PhoneSearchResponse, TransactionSearchPayload,
+    TransactionSearchResponse,
+};
+use crate::error::Error;
+use crate::graphql::{GraphQlBusiness, GraphQlRequest, GraphQlReviews, GraphQlSearch};
+use crate::models::{Coordinates, Event};
+use crate::response_meta::ResponseMeta;
+use crate::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+use futures::TryFutureExt;
+use futures::future::BoxFuture;
+pub use mock_yelp_api::*;
+use serde::de::DeserializeOwned;
+pub use yelp_api_call::*;
+
+/// Endpoints of the Yelp Fusion API, as provided by `YelpFusionClient`.
+///
+/// Take a `&dyn YelpApi` (or an `impl YelpApi`) instead of a `YelpFusionClient` to be able to
+/// substitute a `MockYelpApi` in tests.
+///
+/// Only single requests are covered: the helpers sending several Business Searches
+/// (`business_search_stream`, `search_many` and `business_search_tiled`) are only provided by
+/// `YelpFusionClient`.
+pub trait YelpApi: Send + Sync {
+    /// Searches for businesses.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::business_search`.
+    fn business_search<'a>(
+        &'a self,
+        payload: &'a BusinessSearchPayload,
+    ) -> BoxFuture<'a, Result<BusinessSearchResponse, Error>> {
+        Box::pin(
+            self.business_search_with_meta(payload)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `business_search`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `YelpFusionClient::business_search_with_meta`.
+    fn business_search_with_meta<'a>(
+        &'a self,
+        payload: &'a BusinessSearchPayload,
+    ) -> BoxFuture<'a, Result<(BusinessSearchResponse, ResponseMeta), Error>>;
+
+    /// Gets the details of a business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::business_details`.
+    fn business_details<'a>(
+        &'a self,
+        id_or_alias: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<BusinessDetailsResponse, Error>> {
+        Box::pin(
+            self.business_details_with_meta(id_or_alias, locale)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `business_details`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `YelpFusionClient::business_details_with_meta`.
+    fn business_details_with_meta<'a>(
+        &'a self,
+        id_or_alias: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(BusinessDetailsResponse, ResponseMeta), Error>>;
+
+    /// Gets the reviews of a business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::business_reviews`.
+    fn business_reviews<'a>(
+        &'a self,
+        id_or_alias: &'a str,
+        payload: &'a BusinessReviewsPayload,
+    ) -> BoxFuture<'a, Result<BusinessReviewsResponse, Error>> {
+        Box::pin(
+            self.business_reviews_with_meta(id_or_alias, payload)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `business_reviews`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `YelpFusionClient::business_reviews_with_meta`.
+    fn business_reviews_with_meta<'a>(
+        &'a self,
+        id_or_alias: &'a str,
+        payload: &'a BusinessReviewsPayload,
+    ) -> BoxFuture<'a, Result<(BusinessReviewsResponse, ResponseMeta), Error>>;
+
+    /// Matches business data against Yelp businesses.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::business_match`.
+    fn business_match<'a>(
+        &'a self,
+        payload: &'a BusinessMatchPayload,
+    ) -> BoxFuture<'a, Result<BusinessMatchResponse, Error>> {
+        Box::pin(
+            self.business_match_with_meta(payload)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `business_match`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::business_match_with_meta`.
+    fn business_match_with_meta<'a>(
+        &'a self,
+        payload: &'a BusinessMatchPayload,
+    ) -> BoxFuture<'a, Result<(BusinessMatchResponse, ResponseMeta), Error>>;
+
+    /// Searches for businesses by phone number.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::phone_search`.
+    fn phone_search<'a>(
+        &'a self,
+        payload: &'a PhoneSearchPayload,
+    ) -> BoxFuture<'a, Result<PhoneSearchResponse, Error>> {
+        Box::pin(
+            self.phone_search_with_meta(payload)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `phone_search`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::phone_search_with_meta`.
+    fn phone_search_with_meta<'a>(
+        &'a self,
+        payload: &'a PhoneSearchPayload,
+    ) -> BoxFuture<'a, Result<(PhoneSearchResponse, ResponseMeta), Error>>;
+
+    /// Searches for businesses supporting a transaction type.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::transaction_search`.
+    fn transaction_search<'a>(
+        &'a self,
+        payload: &'a TransactionSearchPayload,
+    ) -> BoxFuture<'a, Result<TransactionSearchResponse, Error>> {
+        Box::pin(
+            self.transaction_search_with_meta(payload)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `transaction_search`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `YelpFusionClient::transaction_search_with_meta`.
+    fn transaction_search_with_meta<'a>(
+        &'a self,
+        payload: &'a TransactionSearchPayload,
+    ) -> BoxFuture<'a, Result<(TransactionSearchResponse, ResponseMeta), Error>>;
+
+    /// Gets autocomplete suggestions for the input text.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::autocomplete`.
+    fn autocomplete<'a>(
+        &'a self,
+        text: &'a str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<AutocompleteResponse, Error>> {
+        Box::pin(
+            self.autocomplete_with_meta(text, coordinates, locale)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `autocomplete`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::autocomplete_with_meta`.
+    fn autocomplete_with_meta<'a>(
+        &'a self,
+        text: &'a str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(AutocompleteResponse, ResponseMeta), Error>>;
+
+    /// Gets all business categories.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::all_categories`.
+    fn all_categories(
+        &self,
+        locale: Option<String>,
+    ) -> BoxFuture<'_, Result<AllCategoriesResponse, Error>> {
+        Box::pin(
+            self.all_categories_with_meta(locale)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `all_categories`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::all_categories_with_meta`.
+    fn all_categories_with_meta(
+        &self,
+        locale: Option<String>,
+    ) -> BoxFuture<'_, Result<(AllCategoriesResponse, ResponseMeta), Error>>;
+
+    /// Gets the details of a business category.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::category_details`.
+    fn category_details<'a>(
+        &'a self,
+        alias: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<CategoryDetailsResponse, Error>> {
+        Box::pin(
+            self.category_details_with_meta(alias, locale)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `category_details`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `YelpFusionClient::category_details_with_meta`.
+    fn category_details_with_meta<'a>(
+        &'a self,
+        alias: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(CategoryDetailsResponse, ResponseMeta), Error>>;
+
+    /// Searches for events.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::event_search`.
+    fn event_search<'a>(
+        &'a self,
+        payload: &'a EventSearchPayload,
+    ) -> BoxFuture<'a, Result<EventSearchResponse, Error>> {
+        Box::pin(
+            self.event_search_with_meta(payload)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `event_search`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::event_search_with_meta`.
+    fn event_search_with_meta<'a>(
+        &'a self,
+        payload: &'a EventSearchPayload,
+    ) -> BoxFuture<'a, Result<(EventSearchResponse, ResponseMeta), Error>>;
+
+    /// Gets the details of an event.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::event_lookup`.
+    fn event_lookup<'a>(
+        &'a self,
+        id: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<Event, Error>> {
+        Box::pin(
+            self.event_lookup_with_meta(id, locale)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `event_lookup`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::event_lookup_with_meta`.
+    fn event_lookup_with_meta<'a>(
+        &'a self,
+        id: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(Event, ResponseMeta), Error>>;
+
+    /// Gets the featured event of a location.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::featured_event`.
+    fn featured_event<'a>(
+        &'a self,
+        payload: &'a FeaturedEventPayload,
+    ) -> BoxFuture<'a, Result<Event, Error>> {
+        Box::pin(
+            self.featured_event_with_meta(payload)
+                .map_ok(|(response, _)| response),
+        )
+    }
+
+    /// Same as `featured_event`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::featured_event_with_meta`.
+    fn featured_event_with_meta<'a>(
+        &'a self,
+        payload: &'a FeaturedEventPayload,
+    ) -> BoxFuture<'a, Result<(Event, ResponseMeta), Error>>;
+
+    /// Sends an arbitrary query to the GraphQL API and deserializes its `data` into `T`.
+    ///
+    /// Being generic, it can't be called through a `dyn YelpApi`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::graphql`.
+    fn graphql<'a, T: DeserializeOwned + Send + 'a>(
+        &'a self,
+        request: &'a GraphQlRequest,
+    ) -> BoxFuture<'a, Result<T, Error>>
+    where
+        Self: Sized;
+
+    /// Fetches a business, and up to `review_limit` of its reviews, through the GraphQL API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::graphql_business`.
+    fn graphql_business<'a>(
+        &'a self,
+        id: &'a str,
+        review_limit: Option<usize>,
+    ) -> BoxFuture<'a, Result<GraphQlBusiness, Error>>;
+
+    /// Fetches many businesses, and up to `review_limit` of their reviews, through the GraphQL API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::graphql_businesses`.
+    fn graphql_businesses<'a>(
+        &'a self,
+        ids: &'a [String],
+        review_limit: Option<usize>,
+    ) -> BoxFuture<'a, Result<Vec<Option<GraphQlBusiness>>, Error>>;
+
+    /// Searches for businesses, and up to `review_limit` of their reviews, through the GraphQL API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::graphql_search`.
+    fn graphql_search<'a>(
+        &'a self,
+        payload: &'a BusinessSearchPayload,
+        review_limit: Option<usize>,
+    ) -> BoxFuture<'a, Result<GraphQlSearch, Error>>;
+
+    /// Fetches a page of reviews of a business through the GraphQL API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `YelpFusionClient::graphql_reviews`.
+    fn graphql_reviews<'a>(
+        &'a self,
+        id: &'a str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> BoxFuture<'a, Result<GraphQlReviews, Error>>;
+}
+
+/// Endpoints of the Yelp Fusion API, as provided by `BlockingYelpFusionClient`.
+///
+/// Take a `&dyn BlockingYelpApi` (or an `impl BlockingYelpApi`) instead of a
+/// `BlockingYelpFusionClient` to be able to substitute a `MockYelpApi` in tests.
+///
+/// Only single requests are covered: the helpers sending several Business Searches
+/// (`business_search_iter`, `search_many` and `business_search_tiled`) are only provided by
+/// `BlockingYelpFusionClient`.
+pub trait BlockingYelpApi: Send + Sync {
+    /// Searches for businesses.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::business_search`.
+    fn business_search(
+        &self,
+        payload: &BusinessSearchPayload,
+    ) -> Result<BusinessSearchResponse, Error> {
+        self.business_search_with_meta(payload)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `business_search`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::business_search_with_meta`.
+    fn business_search_with_meta(
+        &self,
+        payload: &BusinessSearchPayload,
+    ) -> Result<(BusinessSearchResponse, ResponseMeta), Error>;
+
+    /// Gets the details of a business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::business_details`.
+    fn business_details(
+        &self,
+        id_or_alias: &str,
+        locale: Option<String>,
+    ) -> Result<BusinessDetailsResponse, Error> {
+        self.business_details_with_meta(id_or_alias, locale)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `business_details`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::business_details_with_meta`.
+    fn business_details_with_meta(
+        &self,
+        id_or_alias: &str,
+        locale: Option<String>,
+    ) -> Result<(BusinessDetailsResponse, ResponseMeta), Error>;
+
+    /// Gets the reviews of a business.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::business_reviews`.
+    fn business_reviews(
+        &self,
+        id_or_alias: &str,
+        payload: &BusinessReviewsPayload,
+    ) -> Result<BusinessReviewsResponse, Error> {
+        self.business_reviews_with_meta(id_or_alias, payload)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `business_reviews`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::business_reviews_with_meta`.
+    fn business_reviews_with_meta(
+        &self,
+        id_or_alias: &str,
+        payload: &BusinessReviewsPayload,
+    ) -> Result<(BusinessReviewsResponse, ResponseMeta), Error>;
+
+    /// Matches business data against Yelp businesses.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::business_match`.
+    fn business_match(
+        &self,
+        payload: &BusinessMatchPayload,
+    ) -> Result<BusinessMatchResponse, Error> {
+        self.business_match_with_meta(payload)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `business_match`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::business_match_with_meta`.
+    fn business_match_with_meta(
+        &self,
+        payload: &BusinessMatchPayload,
+    ) -> Result<(BusinessMatchResponse, ResponseMeta), Error>;
+
+    /// Searches for businesses by phone number.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::phone_search`.
+    fn phone_search(&self, payload: &PhoneSearchPayload) -> Result<PhoneSearchResponse, Error> {
+        self.phone_search_with_meta(payload)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `phone_search`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::phone_search_with_meta`.
+    fn phone_search_with_meta(
+        &self,
+        payload: &PhoneSearchPayload,
+    ) -> Result<(PhoneSearchResponse, ResponseMeta), Error>;
+
+    /// Searches for businesses supporting a transaction type.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::transaction_search`.
+    fn transaction_search(
+        &self,
+        payload: &TransactionSearchPayload,
+    ) -> Result<TransactionSearchResponse, Error> {
+        self.transaction_search_with_meta(payload)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `transaction_search`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::transaction_search_with_meta`.
+    fn transaction_search_with_meta(
+        &self,
+        payload: &TransactionSearchPayload,
+    ) -> Result<(TransactionSearchResponse, ResponseMeta), Error>;
+
+    /// Gets autocomplete suggestions for the input text.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::autocomplete`.
+    fn autocomplete(
+        &self,
+        text: &str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> Result<AutocompleteResponse, Error> {
+        self.autocomplete_with_meta(text, coordinates, locale)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `autocomplete`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::autocomplete_with_meta`.
+    fn autocomplete_with_meta(
+        &self,
+        text: &str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> Result<(AutocompleteResponse, ResponseMeta), Error>;
+
+    /// Gets all business categories.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::all_categories`.
+    fn all_categories(&self, locale: Option<String>) -> Result<AllCategoriesResponse, Error> {
+        self.all_categories_with_meta(locale)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `all_categories`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::all_categories_with_meta`.
+    fn all_categories_with_meta(
+        &self,
+        locale: Option<String>,
+    ) -> Result<(AllCategoriesResponse, ResponseMeta), Error>;
+
+    /// Gets the details of a business category.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::category_details`.
+    fn category_details(
+        &self,
+        alias: &str,
+        locale: Option<String>,
+    ) -> Result<CategoryDetailsResponse, Error> {
+        self.category_details_with_meta(alias, locale)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `category_details`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::category_details_with_meta`.
+    fn category_details_with_meta(
+        &self,
+        alias: &str,
+        locale: Option<String>,
+    ) -> Result<(CategoryDetailsResponse, ResponseMeta), Error>;
+
+    /// Searches for events.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::event_search`.
+    fn event_search(&self, payload: &EventSearchPayload) -> Result<EventSearchResponse, Error> {
+        self.event_search_with_meta(payload)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `event_search`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::event_search_with_meta`.
+    fn event_search_with_meta(
+        &self,
+        payload: &EventSearchPayload,
+    ) -> Result<(EventSearchResponse, ResponseMeta), Error>;
+
+    /// Gets the details of an event.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::event_lookup`.
+    fn event_lookup(&self, id: &str, locale: Option<String>) -> Result<Event, Error> {
+        self.event_lookup_with_meta(id, locale)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `event_lookup`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::event_lookup_with_meta`.
+    fn event_lookup_with_meta(
+        &self,
+        id: &str,
+        locale: Option<String>,
+    ) -> Result<(Event, ResponseMeta), Error>;
+
+    /// Gets the featured event of a location.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::featured_event`.
+    fn featured_event(&self, payload: &FeaturedEventPayload) -> Result<Event, Error> {
+        self.featured_event_with_meta(payload)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `featured_event`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::featured_event_with_meta`.
+    fn featured_event_with_meta(
+        &self,
+        payload: &FeaturedEventPayload,
+    ) -> Result<(Event, ResponseMeta), Error>;
+
+    /// Sends an arbitrary query to the GraphQL API and deserializes its `data` into `T`.
+    ///
+    /// Being generic, it can't be called through a `dyn BlockingYelpApi`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::graphql`.
+    fn graphql<T: DeserializeOwned>(&self, request: &GraphQlRequest) -> Result<T, Error>
+    where
+        Self: Sized;
+
+    /// Fetches a business, and up to `review_limit` of its reviews, through the GraphQL API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::graphql_business`.
+    fn graphql_business(
+        &self,
+        id: &str,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlBusiness, Error>;
+
+    /// Fetches many businesses, and up to `review_limit` of their reviews, through the GraphQL API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as
+    /// `BlockingYelpFusionClient::graphql_businesses`.
+    fn graphql_businesses(
+        &self,
+        ids: &[String],
+        review_limit: Option<usize>,
+    ) -> Result<Vec<Option<GraphQlBusiness>>, Error>;
+
+    /// Searches for businesses, and up to `review_limit` of their reviews, through the GraphQL API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::graphql_search`.
+    fn graphql_search(
+        &self,
+        payload: &BusinessSearchPayload,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlSearch, Error>;
+
+    /// Fetches a page of reviews of a business through the GraphQL API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `BlockingYelpFusionClient::graphql_reviews`.
+    fn graphql_reviews(
+        &self,
+        id: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<GraphQlReviews, Error>;
+}
+
+impl YelpApi for YelpFusionClient {
+    fn business_search_with_meta<'a>(
+        &'a self,
+        payload: &'a BusinessSearchPayload,
+    ) -> BoxFuture<'a, Result<(BusinessSearchResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::business_search_with_meta(self, payload))
+    }
+
+    fn business_details_with_meta<'a>(
+        &'a self,
+        id_or_alias: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(BusinessDetailsResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::business_details_with_meta(
+            self,
+            id_or_alias,
+            locale,
+        ))
+    }
+
+    fn business_reviews_with_meta<'a>(
+        &'a self,
+        id_or_alias: &'a str,
+        payload: &'a BusinessReviewsPayload,
+    ) -> BoxFuture<'a, Result<(BusinessReviewsResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::business_reviews_with_meta(
+            self,
+            id_or_alias,
+            payload,
+        ))
+    }
+
+    fn business_match_with_meta<'a>(
+        &'a self,
+        payload: &'a BusinessMatchPayload,
+    ) -> BoxFuture<'a, Result<(BusinessMatchResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::business_match_with_meta(self, payload))
+    }
+
+    fn phone_search_with_meta<'a>(
+        &'a self,
+        payload: &'a PhoneSearchPayload,
+    ) -> BoxFuture<'a, Result<(PhoneSearchResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::phone_search_with_meta(self, payload))
+    }
+
+    fn transaction_search_with_meta<'a>(
+        &'a self,
+        payload: &'a TransactionSearchPayload,
+    ) -> BoxFuture<'a, Result<(TransactionSearchResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::transaction_search_with_meta(
+            self, payload,
+        ))
+    }
+
+    fn autocomplete_with_meta<'a>(
+        &'a self,
+        text: &'a str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(AutocompleteResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::autocomplete_with_meta(
+            self,
+            text,
+            coordinates,
+            locale,
+        ))
+    }
+
+    fn all_categories_with_meta(
+        &self,
+        locale: Option<String>,
+    ) -> BoxFuture<'_, Result<(AllCategoriesResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::all_categories_with_meta(self, locale))
+    }
+
+    fn category_details_with_meta<'a>(
+        &'a self,
+        alias: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(CategoryDetailsResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::category_details_with_meta(
+            self, alias, locale,
+        ))
+    }
+
+    fn event_search_with_meta<'a>(
+        &'a self,
+        payload: &'a EventSearchPayload,
+    ) -> BoxFuture<'a, Result<(EventSearchResponse, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::event_search_with_meta(self, payload))
+    }
+
+    fn event_lookup_with_meta<'a>(
+        &'a self,
+        id: &'a str,
+        locale: Option<String>,
+    ) -> BoxFuture<'a, Result<(Event, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::event_lookup_with_meta(self, id, locale))
+    }
+
+    fn featured_event_with_meta<'a>(
+        &'a self,
+        payload: &'a FeaturedEventPayload,
+    ) -> BoxFuture<'a, Result<(Event, ResponseMeta), Error>> {
+        Box::pin(YelpFusionClient::featured_event_with_meta(self, payload))
+    }
+
+    fn graphql<'a, T: DeserializeOwned + Send + 'a>(
+        &'a self,
+        request: &'a GraphQlRequest,
+    ) -> BoxFuture<'a, Result<T, Error>> {
+        Box::pin(YelpFusionClient::graphql(self, request))
+    }
+
+    fn graphql_business<'a>(
+        &'a self,
+        id: &'a str,
+        review_limit: Option<usize>,
+    ) -> BoxFuture<'a, Result<GraphQlBusiness, Error>> {
+        Box::pin(YelpFusionClient::graphql_business(self, id, review_limit))
+    }
+
+    fn graphql_businesses<'a>(
+        &'a self,
+        ids: &'a [String],
+        review_limit: Option<usize>,
+    ) -> BoxFuture<'a, Result<Vec<Option<GraphQlBusiness>>, Error>> {
+        Box::pin(YelpFusionClient::graphql_businesses(
+            self,
+            ids,
+            review_limit,
+        ))
+    }
+
+    fn graphql_search<'a>(
+        &'a self,
+        payload: &'a BusinessSearchPayload,
+        review_limit: Option<usize>,
+    ) -> BoxFuture<'a, Result<GraphQlSearch, Error>> {
+        Box::pin(YelpFusionClient::graphql_search(
+            self,
+            payload,
+            review_limit,
+        ))
+    }
+
+    fn graphql_reviews<'a>(
+        &'a self,
+        id: &'a str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> BoxFuture<'a, Result<GraphQlReviews, Error>> {
+        Box::pin(YelpFusionClient::graphql_reviews(self, id, limit, offset))
+    }
+}
+
+impl BlockingYelpApi for BlockingYelpFusionClient {
+    fn business_search_with_meta(
+        &self,
+        payload: &BusinessSearchPayload,
+    ) -> Result<(BusinessSearchResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::business_search_with_meta(self, payload)
+    }
+
+    fn business_details_with_meta(
+        &self,
+        id_or_alias: &str,
+        locale: Option<String>,
+    ) -> Result<(BusinessDetailsResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::business_details_with_meta(self, id_or_alias, locale)
+    }
+
+    fn business_reviews_with_meta(
+        &self,
+        id_or_alias: &str,
+        payload: &BusinessReviewsPayload,
+    ) -> Result<(BusinessReviewsResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::business_reviews_with_meta(self, id_or_alias, payload)
+    }
+
+    fn business_match_with_meta(
+        &self,
+        payload: &BusinessMatchPayload,
+    ) -> Result<(BusinessMatchResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::business_match_with_meta(self, payload)
+    }
+
+    fn phone_search_with_meta(
+        &self,
+        payload: &PhoneSearchPayload,
+    ) -> Result<(PhoneSearchResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::phone_search_with_meta(self, payload)
+    }
+
+    fn transaction_search_with_meta(
+        &self,
+        payload: &TransactionSearchPayload,
+    ) -> Result<(TransactionSearchResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::transaction_search_with_meta(self, payload)
+    }
+
+    fn autocomplete_with_meta(
+        &self,
+        text: &str,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    ) -> Result<(AutocompleteResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::autocomplete_with_meta(self, text, coordinates, locale)
+    }
+
+    fn all_categories_with_meta(
+        &self,
+        locale: Option<String>,
+    ) -> Result<(AllCategoriesResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::all_categories_with_meta(self, locale)
+    }
+
+    fn category_details_with_meta(
+        &self,
+        alias: &str,
+        locale: Option<String>,
+    ) -> Result<(CategoryDetailsResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::category_details_with_meta(self, alias, locale)
+    }
+
+    fn event_search_with_meta(
+        &self,
+        payload: &EventSearchPayload,
+    ) -> Result<(EventSearchResponse, ResponseMeta), Error> {
+        BlockingYelpFusionClient::event_search_with_meta(self, payload)
+    }
+
+    fn event_lookup_with_meta(
+        &self,
+        id: &str,
+        locale: Option<String>,
+    ) -> Result<(Event, ResponseMeta), Error> {
+        BlockingYelpFusionClient::event_lookup_with_meta(self, id, locale)
+    }
+
+    fn featured_event_with_meta(
+        &self,
+        payload: &FeaturedEventPayload,
+    ) -> Result<(Event, ResponseMeta), Error> {
+        BlockingYelpFusionClient::featured_event_with_meta(self, payload)
+    }
+
+    fn graphql<T: DeserializeOwned>(&self, request: &GraphQlRequest) -> Result<T, Error> {
+        BlockingYelpFusionClient::graphql(self, request)
+    }
+
+    fn graphql_business(
+        &self,
+        id: &str,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlBusiness, Error> {
+        BlockingYelpFusionClient::graphql_business(self, id, review_limit)
+    }
+
+    fn graphql_businesses(
+        &self,
+        ids: &[String],
+        review_limit: Option<usize>,
+    ) -> Result<Vec<Option<GraphQlBusiness>>, Error> {
+        BlockingYelpFusionClient::graphql_businesses(self, ids, review_limit)
+    }
+
+    fn graphql_search(
+        &self,
+        payload: &BusinessSearchPayload,
+        review_limit: Option<usize>,
+    ) -> Result<GraphQlSearch, Error> {
+        BlockingYelpFusionClient::graphql_search(self, payload, review_limit)
+    }
+
+    fn graphql_reviews(
+        &self,
+        id: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<GraphQlReviews, Error> {
+        BlockingYelpFusionClient::graphql_reviews(self, id, limit, offset)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::endpoints::{
+    BusinessMatchPayload, BusinessReviewsPayload, BusinessSearchPayload, Endpoint,
+    EventSearchPayload, FeaturedEventPayload, PhoneSearchPayload, TransactionSearchPayload,
+};
+use crate::graphql::GraphQlRequest;
+use crate::models::Coordinates;
+
+/// Call made to a `MockYelpApi`, with its arguments.
+///
+/// Calls to the `_with_meta` variants of the endpoints are recorded like calls to the endpoints
+/// themselves.
+#[derive(Debug, Clone)]
+pub enum YelpApiCall {
+    BusinessSearch(BusinessSearchPayload),
+    BusinessDetails {
+        id_or_alias: String,
+        locale: Option<String>,
+    },
+    BusinessReviews {
+        id_or_alias: String,
+        payload: BusinessReviewsPayload,
+    },
+    BusinessMatch(BusinessMatchPayload),
+    PhoneSearch(PhoneSearchPayload),
+    TransactionSearch(TransactionSearchPayload),
+    Autocomplete {
+        text: String,
+        coordinates: Option<Coordinates>,
+        locale: Option<String>,
+    },
+    AllCategories {
+        locale: Option<String>,
+    },
+    CategoryDetails {
+        alias: String,
+        locale: Option<String>,
+    },
+    EventSearch(EventSearchPayload),
+    EventLookup {
+        id: String,
+        locale: Option<String>,
+    },
+    FeaturedEvent(FeaturedEventPayload),
+    GraphQl(GraphQlRequest),
+    GraphQlBusiness {
+        id: String,
+        review_limit: Option<usize>,
+    },
+    GraphQlBusinesses {
+        ids: Vec<String>,
+        review_limit: Option<usize>,
+    },
+    GraphQlSearch {
+        payload: BusinessSearchPayload,
+        review_limit: Option<usize>,
+    },
+    GraphQlReviews {
+        id: String,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    },
+}
+
+impl YelpApiCall {
+    /// The REST endpoint that was called, or `None` for calls to the GraphQL API.
+    #[must_use]
+    pub const fn endpoint(&self) -> Option<Endpoint> {
+        match self {
+            Self::BusinessSearch(_) => Some(Endpoint::BusinessSearch),
+            Self::BusinessDetails { .. } => Some(Endpoint::BusinessDetails),
+            Self::BusinessReviews { .. } => Some(Endpoint::BusinessReviews),
+            Self::BusinessMatch(_) => Some(Endpoint::BusinessMatch),
+            Self::PhoneSearch(_) => Some(Endpoint::PhoneSearch),
+            Self::TransactionSearch(_) => Some(Endpoint::TransactionSearch),
+            Self::Autocomplete { .. } => Some(Endpoint::Autocomplete),
+            Self::AllCategories { .. } => Some(Endpoint::AllCategories),
+            Self::CategoryDetails { .. } => Some(Endpoint::CategoryDetails),
+            Self::EventSearch(_) => Some(Endpoint::EventSearch),
+            Self::EventLookup { .. } => Some(Endpoint::EventLookup),
+            Self::FeaturedEvent(_) => Some(Endpoint::FeaturedEvent),
+            Self::GraphQl(_)
+            | Self::GraphQlBusiness { .. }
+            | Self::GraphQlBusinesses { .. }
+            | Self::GraphQlSearch { .. }
+            | Self::GraphQlReviews { .. } => None,
+        }
+    }
+
+    /// Name of the method that was called, e.g. `business_search` or `graphql_business`.
+    #[must_use]
+    pub const fn method(&self) -> &'static str {
+        match self {
+            Self::BusinessSearch(_) => "business_search",
+            Self::BusinessDetails { .. } => "business_details",
+            Self::BusinessReviews { .. } => "business_reviews",
+            Self::BusinessMatch(_) => "business_match",
+            Self::PhoneSearch(_) => "phone_search",
+            Self::TransactionSearch(_) => "transaction_search",
+            Self::Autocomplete { .. } => "autocomplete",
+            Self::AllCategories { .. } => "all_categories",
+            Self::CategoryDetails { .. } => "category_details",
+            Self::EventSearch(_) => "event_search",
+            Self::EventLookup { .. } => "event_lookup",
+            Self::FeaturedEvent(_) => "featured_event",
+            Self::GraphQl(_) => "graphql",
+            Self::GraphQlBusiness { .. } => "graphql_business",
+            Self::GraphQlBusinesses { .. } => "graphql_businesses",
+            Self::GraphQlSearch { .. } => "graphql_search",
+            Self::GraphQlReviews { .. } => "graphql_reviews",
+        }
+    }
+}
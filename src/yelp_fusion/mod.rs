@@ -0,0 +1,748 @@
+mod blocking_yelp_fusion_client_builder;
+mod yelp_fusion_client_builder;
+
+pub use blocking_yelp_fusion_client_builder::*;
+pub use yelp_fusion_client_builder::*;
+
+use crate::api_key::{ApiKey, ApiKeyError, ApiKeyPool, is_bench_error};
+use crate::cache::ResponseCache;
+use crate::endpoints::Endpoint;
+use crate::error::{ApiErrorResponse, Error, MAX_ERROR_BODY_LEN};
+use crate::rate_limiter::RateLimiter;
+use crate::response_meta::{RateLimit, ResponseMeta};
+use crate::retry::{self, RetryPolicy};
+#[cfg(feature = "reqwest")]
+use crate::transport::{BlockingReqwestTransport, ReqwestTransport};
+use crate::transport::{BlockingTransport, Transport, TransportRequest, TransportResponse};
+use bytes::Bytes;
+use http::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue};
+use http::{Method, StatusCode};
+#[cfg(feature = "reqwest")]
+use reqwest::Client;
+#[cfg(feature = "reqwest")]
+use reqwest::blocking::Client as BlockingClient;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+#[cfg(feature = "reqwest")]
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use url::Url;
+
+pub const BASE_URL: &str = "https://api.yelp.com/v3";
+
+#[derive(Debug, Clone)]
+#[expect(clippy::module_name_repetitions)]
+pub struct YelpFusionClient {
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) base_url: String,
+    pub(crate) api_key: ApiKey,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) last_rate_limit: Arc<Mutex<Option<RateLimit>>>,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) default_locale: Option<String>,
+    pub(crate) key_pool: Option<ApiKeyPool>,
+    pub(crate) cache: Option<ResponseCache>,
+    pub(crate) default_headers: HeaderMap,
+}
+
+impl YelpFusionClient {
+    #[cfg(feature = "reqwest")]
+    /// Creates a client using `client` (or a default `reqwest::Client`) to send requests.
+    ///
+    /// Use `YelpFusionClient::builder` to configure the base URL, timeouts, user agent, transport
+    /// and more.
+    #[must_use]
+    pub fn new<K: Into<ApiKey>>(api_key: K, client: Option<Client>) -> Self {
+        Self {
+            transport: Arc::new(ReqwestTransport::new(client.unwrap_or_default())),
+            base_url: BASE_URL.to_string(),
+            api_key: api_key.into(),
+            retry_policy: RetryPolicy::none(),
+            last_rate_limit: Arc::default(),
+            rate_limiter: None,
+            default_locale: None,
+            key_pool: None,
+            cache: None,
+            default_headers: HeaderMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn builder<K: Into<ApiKey>>(api_key: K) -> YelpFusionClientBuilder {
+        YelpFusionClientBuilder::new(api_key)
+    }
+
+    /// Creates a builder of a client sending every request with a key of `key_pool`.
+    #[must_use]
+    pub fn builder_with_key_pool(key_pool: ApiKeyPool) -> YelpFusionClientBuilder {
+        YelpFusionClientBuilder::from_key_pool(key_pool)
+    }
+
+    #[cfg(feature = "reqwest")]
+    /// Creates a client whose API key is read from the `YELP_FUSION_API_KEY` environment variable.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `ApiKey::from_env`.
+    pub fn from_env(client: Option<Client>) -> Result<Self, ApiKeyError> {
+        Ok(Self::new(ApiKey::from_env()?, client))
+    }
+
+    #[cfg(feature = "reqwest")]
+    /// Creates a client whose API key is read from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `ApiKey::from_key_file`.
+    pub fn from_key_file<P: AsRef<Path>>(
+        path: P,
+        client: Option<Client>,
+    ) -> Result<Self, ApiKeyError> {
+        Ok(Self::new(ApiKey::from_key_file(path)?, client))
+    }
+
+    /// Retries failed requests of every endpoint according to `retry_policy`.
+    ///
+    /// By default, requests are not retried.
+    #[must_use]
+    pub const fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Throttles every request (including retries) through `rate_limiter`.
+    ///
+    /// By default, requests are not throttled.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sends every request with a key of `key_pool` instead of the client's own API key.
+    #[must_use]
+    pub fn with_key_pool(mut self, key_pool: ApiKeyPool) -> Self {
+        self.key_pool = Some(key_pool);
+        self
+    }
+
+    /// Serves `GET` requests from `cache` when possible, and caches their successful responses.
+    ///
+    /// By default, responses are not cached.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sends every request through `transport` instead of a `ReqwestTransport`.
+    #[must_use]
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// The daily quota reported by the most recent response carrying `RateLimit-*` headers,
+    /// across all clones of this client.
+    ///
+    /// Returns `None` until such a response has been received.
+    #[must_use]
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.last_rate_limit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Sends an authenticated `GET` request to `path` (relative to the base URL) and deserializes
+    /// the response body into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `T`.
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query_params: &[(&str, String)],
+    ) -> Result<T, Error> {
+        self.get_with_meta(path, query_params)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `get`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `get`.
+    pub(crate) async fn get_with_meta<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query_params: &[(&str, String)],
+    ) -> Result<(T, ResponseMeta), Error> {
+        // fall back to the default locale
+        let query_params: Vec<(&str, String)> =
+            with_default_locale(path, query_params, self.default_locale.as_ref());
+
+        // serve from the cache, if possible
+        let start: Instant = Instant::now();
+        let cache_key: Option<String> = self
+            .cache
+            .as_ref()
+            .map(|_| ResponseCache::key(&self.base_url, path, &query_params));
+        if let (Some(cache), Some(cache_key)) = (&self.cache, &cache_key) {
+            if let Some(bytes) = cache.get(cache_key) {
+                let response: T = decode_response(StatusCode::OK, &bytes)?;
+                return Ok((response, ResponseMeta::cached(start.elapsed())));
+            }
+        }
+
+        // create request
+        let request: TransportRequest = new_request(
+            Method::GET,
+            &self.base_url,
+            path,
+            &query_params,
+            &self.default_headers,
+            None,
+        )?;
+
+        let (bytes, meta) = self.send(&request).await?;
+        let response: T = decode_response(meta.status_code, &bytes)?;
+        if let (Some(cache), Some(cache_key)) = (&self.cache, &cache_key) {
+            cache.insert(path, cache_key, bytes);
+        }
+        Ok((response, meta))
+    }
+
+    /// Sends an authenticated `POST` request with a JSON `body` to `path` (relative to the base
+    /// URL) and deserializes the response body into `T`, returning it along with its
+    /// `ResponseMeta`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `T`.
+    pub(crate) async fn post_with_meta<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<(T, ResponseMeta), Error> {
+        // create request
+        let body: Bytes = Bytes::from(serde_json::to_vec(body)?);
+        let request: TransportRequest = new_request(
+            Method::POST,
+            &self.base_url,
+            path,
+            &[],
+            &self.default_headers,
+            Some(body),
+        )?;
+
+        let (bytes, meta) = self.send(&request).await?;
+        let response: T = decode_response(meta.status_code, &bytes)?;
+        Ok((response, meta))
+    }
+
+    /// Sends `request`, returning the body of its successful response.
+    async fn send(&self, request: &TransportRequest) -> Result<(Bytes, ResponseMeta), Error> {
+        let key_count: usize = self.key_pool.as_ref().map_or(1, ApiKeyPool::len);
+        let mut attempt: u32 = 1;
+        let mut rotations: usize = 0;
+        loop {
+            // wait for the client-side rate limiter
+            if let Some(rate_limiter) = &self.rate_limiter {
+                tokio::time::sleep(rate_limiter.acquire()?).await;
+            }
+
+            // authenticate request
+            let (key_index, api_key) = self.select_api_key()?;
+            let attempt_request: TransportRequest = authorize(request.clone(), &api_key)?;
+
+            // retry with backoff, as long as the policy allows it
+            let (result, retry_after) = self.send_once(attempt_request, key_index).await;
+            match result {
+                // switch to another key of the pool right away
+                Err(e) if is_bench_error(&e) && rotations + 1 < key_count => {
+                    rotations += 1;
+                }
+                Err(e) if e.is_retryable() => {
+                    let Some(delay) = self.retry_policy.delay(attempt, retry_after) else {
+                        return Err(e);
+                    };
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends `request` once, returning its result along with the `Retry-After` delay requested by
+    /// the server (if any).
+    async fn send_once(
+        &self,
+        request: TransportRequest,
+        key_index: Option<usize>,
+    ) -> (Result<(Bytes, ResponseMeta), Error>, Option<Duration>) {
+        // send request, get response
+        let start: Instant = Instant::now();
+        let TransportResponse {
+            status_code,
+            headers,
+            body: bytes,
+        } = match self.transport.send(request).await {
+            Ok(response) => response,
+            Err(e) => {
+                self.record_key_usage(key_index, None, Some(&e));
+                return (Err(e), None);
+            }
+        };
+        let retry_after: Option<Duration> = retry::retry_after(&headers);
+
+        // remember the latest known quota
+        let meta: ResponseMeta = ResponseMeta::new(status_code, &headers, start.elapsed());
+        record_rate_limit(&self.last_rate_limit, meta.rate_limit.as_ref());
+
+        let result: Result<(), Error> = check_status(&meta, headers, &bytes);
+        self.record_key_usage(key_index, meta.rate_limit.as_ref(), result.as_ref().err());
+        (result.map(|()| (bytes, meta)), retry_after)
+    }
+
+    /// Picks the API key of the next request: the client's own key, or the best key of its
+    /// pool along with its index.
+    fn select_api_key(&self) -> Result<(Option<usize>, ApiKey), Error> {
+        match &self.key_pool {
+            Some(key_pool) => key_pool
+                .select()
+                .map(|(index, api_key)| (Some(index), api_key)),
+            None => Ok((None, self.api_key.clone())),
+        }
+    }
+
+    fn record_key_usage(
+        &self,
+        key_index: Option<usize>,
+        rate_limit: Option<&RateLimit>,
+        error: Option<&Error>,
+    ) {
+        if let (Some(key_pool), Some(index)) = (&self.key_pool, key_index) {
+            key_pool.record(index, rate_limit, error);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockingYelpFusionClient {
+    pub(crate) transport: Arc<dyn BlockingTransport>,
+    pub(crate) base_url: String,
+    pub(crate) api_key: ApiKey,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) last_rate_limit: Arc<Mutex<Option<RateLimit>>>,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) default_locale: Option<String>,
+    pub(crate) key_pool: Option<ApiKeyPool>,
+    pub(crate) cache: Option<ResponseCache>,
+    pub(crate) default_headers: HeaderMap,
+}
+
+impl BlockingYelpFusionClient {
+    #[cfg(feature = "reqwest")]
+    /// Creates a client using `client` (or a default `reqwest::blocking::Client`) to send
+    /// requests.
+    ///
+    /// Use `BlockingYelpFusionClient::builder` to configure the base URL, timeouts, user agent,
+    /// transport and more.
+    #[must_use]
+    pub fn new<K: Into<ApiKey>>(api_key: K, client: Option<BlockingClient>) -> Self {
+        Self {
+            transport: Arc::new(BlockingReqwestTransport::new(client.unwrap_or_default())),
+            base_url: BASE_URL.to_string(),
+            api_key: api_key.into(),
+            retry_policy: RetryPolicy::none(),
+            last_rate_limit: Arc::default(),
+            rate_limiter: None,
+            default_locale: None,
+            key_pool: None,
+            cache: None,
+            default_headers: HeaderMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn builder<K: Into<ApiKey>>(api_key: K) -> BlockingYelpFusionClientBuilder {
+        BlockingYelpFusionClientBuilder::new(api_key)
+    }
+
+    /// Creates a builder of a client sending every request with a key of `key_pool`.
+    #[must_use]
+    pub fn builder_with_key_pool(key_pool: ApiKeyPool) -> BlockingYelpFusionClientBuilder {
+        BlockingYelpFusionClientBuilder::from_key_pool(key_pool)
+    }
+
+    #[cfg(feature = "reqwest")]
+    /// Creates a client whose API key is read from the `YELP_FUSION_API_KEY` environment variable.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `ApiKey::from_env`.
+    pub fn from_env(client: Option<BlockingClient>) -> Result<Self, ApiKeyError> {
+        Ok(Self::new(ApiKey::from_env()?, client))
+    }
+
+    #[cfg(feature = "reqwest")]
+    /// Creates a client whose API key is read from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `ApiKey::from_key_file`.
+    pub fn from_key_file<P: AsRef<Path>>(
+        path: P,
+        client: Option<BlockingClient>,
+    ) -> Result<Self, ApiKeyError> {
+        Ok(Self::new(ApiKey::from_key_file(path)?, client))
+    }
+
+    /// Retries failed requests of every endpoint according to `retry_policy`.
+    ///
+    /// By default, requests are not retried.
+    #[must_use]
+    pub const fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Throttles every request (including retries) through `rate_limiter`.
+    ///
+    /// By default, requests are not throttled.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sends every request with a key of `key_pool` instead of the client's own API key.
+    #[must_use]
+    pub fn with_key_pool(mut self, key_pool: ApiKeyPool) -> Self {
+        self.key_pool = Some(key_pool);
+        self
+    }
+
+    /// Serves `GET` requests from `cache` when possible, and caches their successful responses.
+    ///
+    /// By default, responses are not cached.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sends every request through `transport` instead of a `BlockingReqwestTransport`.
+    #[must_use]
+    pub fn with_transport<T: BlockingTransport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// The daily quota reported by the most recent response carrying `RateLimit-*` headers,
+    /// across all clones of this client.
+    ///
+    /// Returns `None` until such a response has been received.
+    #[must_use]
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.last_rate_limit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Sends an authenticated `GET` request to `path` (relative to the base URL) and deserializes
+    /// the response body into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `T`.
+    pub(crate) fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query_params: &[(&str, String)],
+    ) -> Result<T, Error> {
+        self.get_with_meta(path, query_params)
+            .map(|(response, _)| response)
+    }
+
+    /// Same as `get`, but also returns the `ResponseMeta` of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as `get`.
+    pub(crate) fn get_with_meta<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query_params: &[(&str, String)],
+    ) -> Result<(T, ResponseMeta), Error> {
+        // fall back to the default locale
+        let query_params: Vec<(&str, String)> =
+            with_default_locale(path, query_params, self.default_locale.as_ref());
+
+        // serve from the cache, if possible
+        let start: Instant = Instant::now();
+        let cache_key: Option<String> = self
+            .cache
+            .as_ref()
+            .map(|_| ResponseCache::key(&self.base_url, path, &query_params));
+        if let (Some(cache), Some(cache_key)) = (&self.cache, &cache_key) {
+            if let Some(bytes) = cache.get(cache_key) {
+                let response: T = decode_response(StatusCode::OK, &bytes)?;
+                return Ok((response, ResponseMeta::cached(start.elapsed())));
+            }
+        }
+
+        // create request
+        let request: TransportRequest = new_request(
+            Method::GET,
+            &self.base_url,
+            path,
+            &query_params,
+            &self.default_headers,
+            None,
+        )?;
+
+        let (bytes, meta) = self.send(&request)?;
+        let response: T = decode_response(meta.status_code, &bytes)?;
+        if let (Some(cache), Some(cache_key)) = (&self.cache, &cache_key) {
+            cache.insert(path, cache_key, bytes);
+        }
+        Ok((response, meta))
+    }
+
+    /// Sends an authenticated `POST` request with a JSON `body` to `path` (relative to the base
+    /// URL) and deserializes the response body into `T`, returning it along with its
+    /// `ResponseMeta`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an error occurred while creating/sending the request,
+    /// if it failed to decode the response's bytes, if the response's status code was not a
+    /// success, or if it failed to serialize the response bytes into `T`.
+    pub(crate) fn post_with_meta<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<(T, ResponseMeta), Error> {
+        // create request
+        let body: Bytes = Bytes::from(serde_json::to_vec(body)?);
+        let request: TransportRequest = new_request(
+            Method::POST,
+            &self.base_url,
+            path,
+            &[],
+            &self.default_headers,
+            Some(body),
+        )?;
+
+        let (bytes, meta) = self.send(&request)?;
+        let response: T = decode_response(meta.status_code, &bytes)?;
+        Ok((response, meta))
+    }
+
+    /// Sends `request`, returning the body of its successful response.
+    fn send(&self, request: &TransportRequest) -> Result<(Bytes, ResponseMeta), Error> {
+        let key_count: usize = self.key_pool.as_ref().map_or(1, ApiKeyPool::len);
+        let mut attempt: u32 = 1;
+        let mut rotations: usize = 0;
+        loop {
+            // wait for the client-side rate limiter
+            if let Some(rate_limiter) = &self.rate_limiter {
+                std::thread::sleep(rate_limiter.acquire()?);
+            }
+
+            // authenticate request
+            let (key_index, api_key) = self.select_api_key()?;
+            let attempt_request: TransportRequest = authorize(request.clone(), &api_key)?;
+
+            // retry with backoff, as long as the policy allows it
+            let (result, retry_after) = self.send_once(attempt_request, key_index);
+            match result {
+                // switch to another key of the pool right away
+                Err(e) if is_bench_error(&e) && rotations + 1 < key_count => {
+                    rotations += 1;
+                }
+                Err(e) if e.is_retryable() => {
+                    let Some(delay) = self.retry_policy.delay(attempt, retry_after) else {
+                        return Err(e);
+                    };
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends `request` once, returning its result along with the `Retry-After` delay requested by
+    /// the server (if any).
+    fn send_once(
+        &self,
+        request: TransportRequest,
+        key_index: Option<usize>,
+    ) -> (Result<(Bytes, ResponseMeta), Error>, Option<Duration>) {
+        // send request, get response
+        let start: Instant = Instant::now();
+        let TransportResponse {
+            status_code,
+            headers,
+            body: bytes,
+        } = match self.transport.send(request) {
+            Ok(response) => response,
+            Err(e) => {
+                self.record_key_usage(key_index, None, Some(&e));
+                return (Err(e), None);
+            }
+        };
+        let retry_after: Option<Duration> = retry::retry_after(&headers);
+
+        // remember the latest known quota
+        let meta: ResponseMeta = ResponseMeta::new(status_code, &headers, start.elapsed());
+        record_rate_limit(&self.last_rate_limit, meta.rate_limit.as_ref());
+
+        let result: Result<(), Error> = check_status(&meta, headers, &bytes);
+        self.record_key_usage(key_index, meta.rate_limit.as_ref(), result.as_ref().err());
+        (result.map(|()| (bytes, meta)), retry_after)
+    }
+
+    /// Picks the API key of the next request: the client's own key, or the best key of its
+    /// pool along with its index.
+    fn select_api_key(&self) -> Result<(Option<usize>, ApiKey), Error> {
+        match &self.key_pool {
+            Some(key_pool) => key_pool
+                .select()
+                .map(|(index, api_key)| (Some(index), api_key)),
+            None => Ok((None, self.api_key.clone())),
+        }
+    }
+
+    fn record_key_usage(
+        &self,
+        key_index: Option<usize>,
+        rate_limit: Option<&RateLimit>,
+        error: Option<&Error>,
+    ) {
+        if let (Some(key_pool), Some(index)) = (&self.key_pool, key_index) {
+            key_pool.record(index, rate_limit, error);
+        }
+    }
+}
+
+/// Turns a failed status code and its response body into an `Error`.
+///
+/// Failed responses become `Error::RequestFailed` when the body is one of Yelp's error responses,
+/// and `Error::UnexpectedResponse` otherwise.
+fn check_status(meta: &ResponseMeta, headers: HeaderMap, bytes: &Bytes) -> Result<(), Error> {
+    let status_code: StatusCode = meta.status_code;
+    if status_code.is_success() {
+        return Ok(());
+    }
+
+    Err(match serde_json::from_slice::<ApiErrorResponse>(bytes) {
+        Ok(error_response) => Error::RequestFailed {
+            error_response,
+            status_code,
+            meta: Some(Box::new(meta.clone())),
+        },
+        Err(_) => Error::UnexpectedResponse {
+            status_code,
+            headers: Box::new(headers),
+            body: truncate_body(bytes),
+            meta: Box::new(meta.clone()),
+        },
+    })
+}
+
+/// Deserializes a successful response body into `T`, or into `Error::InvalidResponseBody` if it
+/// can't be deserialized.
+fn decode_response<T: DeserializeOwned>(
+    status_code: StatusCode,
+    bytes: &Bytes,
+) -> Result<T, Error> {
+    serde_json::from_slice(bytes).map_err(|error| Error::InvalidResponseBody {
+        error,
+        status_code,
+        body: truncate_body(bytes),
+    })
+}
+
+/// Creates an unauthenticated request to `path` (relative to `base_url`), with the client's
+/// `default_headers`.
+fn new_request(
+    method: Method,
+    base_url: &str,
+    path: &str,
+    query_params: &[(&str, String)],
+    default_headers: &HeaderMap,
+    body: Option<Bytes>,
+) -> Result<TransportRequest, Error> {
+    let mut url: Url = Url::parse(&format!("{base_url}{path}"))?;
+    if !query_params.is_empty() {
+        url.query_pairs_mut().extend_pairs(query_params);
+    }
+
+    let mut headers: HeaderMap = default_headers.clone();
+    if body.is_some() {
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    }
+
+    Ok(TransportRequest {
+        method,
+        url,
+        headers,
+        body,
+    })
+}
+
+/// Adds the `Authorization` header of `api_key` to `request`.
+fn authorize(mut request: TransportRequest, api_key: &ApiKey) -> Result<TransportRequest, Error> {
+    let mut authorization: HeaderValue =
+        HeaderValue::try_from(format!("Bearer {}", api_key.expose_secret()))
+            .map_err(|_| Error::InvalidApiKey(ApiKeyError::InvalidHeaderValue))?;
+    authorization.set_sensitive(true);
+    request.headers.insert(AUTHORIZATION, authorization);
+    Ok(request)
+}
+
+/// Appends `default_locale` to the `query_params` of a request to `path`, unless they already
+/// set a locale or the endpoint doesn't accept one.
+fn with_default_locale<'a>(
+    path: &str,
+    query_params: &[(&'a str, String)],
+    default_locale: Option<&String>,
+) -> Vec<(&'a str, String)> {
+    let mut query_params: Vec<(&'a str, String)> = query_params.to_vec();
+    if !Endpoint::from_path(path).is_some_and(Endpoint::accepts_locale) {
+        return query_params;
+    }
+    if let Some(locale) = default_locale {
+        if !query_params.iter().any(|(key, _)| *key == "locale") {
+            query_params.push(("locale", locale.clone()));
+        }
+    }
+    query_params
+}
+
+fn record_rate_limit(last_rate_limit: &Mutex<Option<RateLimit>>, rate_limit: Option<&RateLimit>) {
+    if let Some(rate_limit) = rate_limit {
+        *last_rate_limit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(rate_limit.clone());
+    }
+}
+
+fn truncate_body(bytes: &Bytes) -> Bytes {
+    bytes.slice(..bytes.len().min(MAX_ERROR_BODY_LEN))
+}
@@ -0,0 +1,225 @@
+use crate::api_key::{ApiKey, ApiKeyPool};
+use crate::cache::ResponseCache;
+use crate::error::Error;
+use crate::rate_limiter::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::transport::Transport;
+#[cfg(feature = "reqwest")]
+use crate::transport::{ReqwestTransport, transport_error};
+use crate::yelp_fusion::{BASE_URL, YelpFusionClient};
+use http::header::{HeaderMap, HeaderValue, USER_AGENT};
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+#[derive(Debug, Clone)]
+pub struct YelpFusionClientBuilder {
+    /// Yelp Fusion API key used to authenticate every request.
+    ///
+    /// Unused when `key_pool` is set.
+    pub api_key: ApiKey,
+
+    /// Base URL every endpoint path is appended to.
+    ///
+    /// Defaults to `BASE_URL`. Useful to point the client at a proxy or a mock server.
+    pub base_url: String,
+
+    /// Timeout for a whole request, from connecting until the response body has been read.
+    ///
+    /// Only applies to the default transport; `build` fails if it is set along with `transport`.
+    pub timeout: Option<Duration>,
+
+    /// Timeout for only the connect phase of a request.
+    ///
+    /// Only applies to the default transport; `build` fails if it is set along with `transport`.
+    pub connect_timeout: Option<Duration>,
+
+    /// `User-Agent` header sent with every request.
+    pub user_agent: Option<String>,
+
+    /// Locale sent with every request that doesn't set one itself, to the endpoints accepting a
+    /// locale (see `Endpoint::accepts_locale`).
+    pub default_locale: Option<String>,
+
+    /// Headers sent with every request.
+    pub default_headers: HeaderMap,
+
+    /// How failed requests are retried.
+    ///
+    /// Defaults to `RetryPolicy::none()`.
+    pub retry_policy: RetryPolicy,
+
+    /// Client-side limit of the requests sent per second and per day.
+    pub rate_limiter: Option<RateLimiter>,
+
+    /// Keys used instead of `api_key`, picked by remaining daily quota.
+    pub key_pool: Option<ApiKeyPool>,
+
+    /// Cache of successful `GET` responses.
+    pub cache: Option<ResponseCache>,
+
+    /// Transport sending the requests instead of a `ReqwestTransport`.
+    pub transport: Option<Arc<dyn Transport>>,
+}
+
+impl YelpFusionClientBuilder {
+    #[must_use]
+    pub fn new<K: Into<ApiKey>>(api_key: K) -> Self {
+        Self {
+            api_key: api_key.into(),
+            base_url: BASE_URL.to_string(),
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            default_locale: None,
+            default_headers: HeaderMap::new(),
+            retry_policy: RetryPolicy::none(),
+            rate_limiter: None,
+            key_pool: None,
+            cache: None,
+            transport: None,
+        }
+    }
+
+    /// Creates a builder sending every request with a key of `key_pool`, without an API key of
+    /// its own.
+    #[must_use]
+    pub fn from_key_pool(key_pool: ApiKeyPool) -> Self {
+        let mut builder: Self = Self::new(key_pool.first_key());
+        builder.key_pool = Some(key_pool);
+        builder
+    }
+
+    pub fn base_url(&mut self, base_url: String) -> &mut Self {
+        self.base_url = base_url;
+        self
+    }
+
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(&mut self, connect_timeout: Duration) -> &mut Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    pub fn user_agent(&mut self, user_agent: String) -> &mut Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
+
+    pub fn default_locale(&mut self, default_locale: String) -> &mut Self {
+        self.default_locale = Some(default_locale);
+        self
+    }
+
+    pub fn default_headers(&mut self, default_headers: HeaderMap) -> &mut Self {
+        self.default_headers = default_headers;
+        self
+    }
+
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn rate_limiter(&mut self, rate_limiter: RateLimiter) -> &mut Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    pub fn key_pool(&mut self, key_pool: ApiKeyPool) -> &mut Self {
+        self.key_pool = Some(key_pool);
+        self
+    }
+
+    pub fn cache(&mut self, cache: ResponseCache) -> &mut Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn transport<T: Transport + 'static>(&mut self, transport: T) -> &mut Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidUrl` if `base_url` isn't a valid URL.
+    ///
+    /// Returns `Error::TransportError` if `user_agent` isn't a valid header value, or if `timeout`
+    /// or `connect_timeout` is set along with `transport`.
+    ///
+    /// Returns `Error::TransportError` if `transport` isn't set and either the `reqwest` feature is
+    /// disabled, or the default `reqwest::Client` could not be built, e.g. because the TLS backend
+    /// failed to initialize.
+    pub fn build(&self) -> Result<YelpFusionClient, Error> {
+        let base_url: &str = self.base_url.trim_end_matches('/');
+        Url::parse(base_url)?;
+
+        let default_headers: HeaderMap = self.request_headers()?;
+        if self.transport.is_some() && (self.timeout.is_some() || self.connect_timeout.is_some()) {
+            return Err(Error::TransportError {
+                error: "`timeout` and `connect_timeout` only configure the default transport, set \
+                        them on the custom transport instead"
+                    .into(),
+                retryable: false,
+            });
+        }
+
+        let transport: Arc<dyn Transport> = match &self.transport {
+            Some(transport) => Arc::clone(transport),
+            #[cfg(feature = "reqwest")]
+            None => Arc::new(ReqwestTransport::new(self.build_client()?)),
+            #[cfg(not(feature = "reqwest"))]
+            None => {
+                return Err(Error::TransportError {
+                    error: "no transport set, and the `reqwest` feature is disabled".into(),
+                    retryable: false,
+                });
+            }
+        };
+
+        Ok(YelpFusionClient {
+            transport,
+            base_url: base_url.to_string(),
+            api_key: self.api_key.clone(),
+            retry_policy: self.retry_policy,
+            last_rate_limit: Arc::default(),
+            rate_limiter: self.rate_limiter.clone(),
+            default_locale: self.default_locale.clone(),
+            key_pool: self.key_pool.clone(),
+            cache: self.cache.clone(),
+            default_headers,
+        })
+    }
+
+    #[cfg(feature = "reqwest")]
+    fn build_client(&self) -> Result<reqwest::Client, Error> {
+        let mut client_builder = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+        client_builder.build().map_err(transport_error)
+    }
+
+    /// `default_headers`, along with the `User-Agent` header of `user_agent`, which every request
+    /// is sent with whatever its transport.
+    fn request_headers(&self) -> Result<HeaderMap, Error> {
+        let mut headers: HeaderMap = self.default_headers.clone();
+        if let Some(user_agent) = &self.user_agent {
+            let user_agent: HeaderValue =
+                HeaderValue::try_from(user_agent).map_err(|e| Error::TransportError {
+                    error: Box::new(e),
+                    retryable: false,
+                })?;
+            headers.insert(USER_AGENT, user_agent);
+        }
+        Ok(headers)
+    }
+}
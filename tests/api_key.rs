@@ -0,0 +1,73 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use yelp_fusion_rs::api_key::{API_KEY_ENV_VAR, ApiKey, ApiKeyError};
+#[cfg(feature = "reqwest")]
+use yelp_fusion_rs::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+
+fn key_file(name: &str, contents: &str) -> PathBuf {
+    let path: PathBuf =
+        env::temp_dir().join(format!("yelp-fusion-rs-{}-{name}.key", std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_debug_is_redacted() {
+    let api_key: ApiKey = ApiKey::from("super_secret_key");
+    assert_eq!("super_secret_key", api_key.expose_secret());
+    assert!(!format!("{api_key:?}").contains("super_secret_key"));
+}
+
+#[cfg(feature = "reqwest")]
+#[test]
+fn test_client_debug_is_redacted() {
+    let client: YelpFusionClient = YelpFusionClient::new("super_secret_key".to_string(), None);
+    assert!(!format!("{client:?}").contains("super_secret_key"));
+
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("super_secret_key")
+        .build()
+        .unwrap();
+    assert!(!format!("{client:?}").contains("super_secret_key"));
+}
+
+#[test]
+fn test_from_key_file() {
+    let path: PathBuf = key_file("valid", "  super_secret_key\n");
+    let api_key: ApiKey = ApiKey::from_key_file(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!("super_secret_key", api_key.expose_secret());
+}
+
+#[test]
+fn test_from_key_file_empty() {
+    let path: PathBuf = key_file("empty", "\n");
+    let result: Result<ApiKey, ApiKeyError> = ApiKey::from_key_file(&path);
+    fs::remove_file(&path).unwrap();
+    match result {
+        Ok(_) => panic!("Returned an API key!"),
+        Err(ApiKeyError::Empty) => {}
+        Err(e) => panic!("Returned {e}!"),
+    }
+}
+
+#[test]
+fn test_from_key_file_missing() {
+    match ApiKey::from_key_file("/nonexistent/yelp-fusion-rs.key") {
+        Ok(_) => panic!("Returned an API key!"),
+        Err(ApiKeyError::Io(_)) => {}
+        Err(e) => panic!("Returned {e}!"),
+    }
+}
+
+#[test]
+fn test_env_var_error_display() {
+    assert_eq!(
+        "environment variable YELP_FUSION_API_KEY is not set",
+        ApiKeyError::EnvVarNotPresent(API_KEY_ENV_VAR).to_string()
+    );
+    assert_eq!(
+        "environment variable YELP_FUSION_API_KEY is not valid unicode",
+        ApiKeyError::EnvVarNotUnicode(API_KEY_ENV_VAR).to_string()
+    );
+}
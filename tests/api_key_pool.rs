@@ -0,0 +1,114 @@
+#![cfg(feature = "reqwest")]
+
+pub mod common;
+
+use common::{MockResponse, spawn_server};
+use std::time::Duration;
+use yelp_fusion_rs::api_key::{ApiKeyError, ApiKeyPool, ApiKeyStats};
+use yelp_fusion_rs::error::{ApiErrorCode, Error};
+use yelp_fusion_rs::yelp_fusion::BlockingYelpFusionClient;
+
+fn respond(request: &str) -> MockResponse {
+    if request.contains("bearer exhausted") {
+        (
+            "429 Too Many Requests",
+            String::new(),
+            r#"{"error":{"code":"ACCESS_LIMIT_REACHED","description":"limit reached"}}"#
+                .to_string(),
+        )
+    } else if request.contains("bearer reset") {
+        (
+            "200 OK",
+            "RateLimit-Remaining: 1\r\nRateLimit-ResetTime: 2000-01-01T00:00:00+00:00\r\n"
+                .to_string(),
+            r#"{"categories":[]}"#.to_string(),
+        )
+    } else if request.contains("bearer low") {
+        (
+            "200 OK",
+            "RateLimit-Remaining: 10\r\n".to_string(),
+            r#"{"categories":[]}"#.to_string(),
+        )
+    } else {
+        (
+            "200 OK",
+            "RateLimit-Remaining: 5000\r\n".to_string(),
+            r#"{"categories":[]}"#.to_string(),
+        )
+    }
+}
+
+fn client(key_pool: &ApiKeyPool) -> BlockingYelpFusionClient {
+    BlockingYelpFusionClient::builder_with_key_pool(key_pool.clone())
+        .base_url(spawn_server(respond))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_rotates_to_key_with_most_remaining_quota() {
+    let key_pool: ApiKeyPool = ApiKeyPool::new(["exhausted", "low", "high"]).unwrap();
+    let client: BlockingYelpFusionClient = client(&key_pool);
+
+    // "exhausted" gets benched, "low" takes over, then the unknown "high" gets probed
+    assert!(client.all_categories(None).is_ok());
+    assert!(client.all_categories(None).is_ok());
+    assert!(client.all_categories(None).is_ok());
+
+    let stats: Vec<ApiKeyStats> = key_pool.stats();
+    assert_eq!(1, stats[0].requests);
+    assert_eq!(1, stats[0].failures);
+    assert!(stats[0].benched_for.is_some());
+    assert_eq!(
+        Some(ApiErrorCode::AccessLimitReached),
+        stats[0].last_error_code
+    );
+    assert_eq!(1, stats[1].requests);
+    assert_eq!(Some(10), stats[1].rate_limit.as_ref().unwrap().remaining);
+    assert_eq!(2, stats[2].requests);
+    assert_eq!(0, stats[2].failures);
+    assert_eq!(Some(5000), stats[2].rate_limit.as_ref().unwrap().remaining);
+}
+
+#[test]
+fn test_all_keys_benched() {
+    let key_pool: ApiKeyPool = ApiKeyPool::new(["exhausted"])
+        .unwrap()
+        .with_bench_duration(Duration::from_secs(60));
+    let client: BlockingYelpFusionClient = client(&key_pool);
+
+    match client.all_categories(None) {
+        Ok(_) => panic!("Returned a response!"),
+        Err(e) => assert!(e.is_quota_error()),
+    }
+    match client.all_categories(None) {
+        Ok(_) => panic!("Returned a response!"),
+        Err(Error::AllApiKeysBenched { available_in }) => {
+            assert!(available_in <= Duration::from_secs(60));
+        }
+        Err(e) => panic!("Returned {e}!"),
+    }
+}
+
+#[test]
+fn test_ignores_remaining_quota_once_reset() {
+    let key_pool: ApiKeyPool = ApiKeyPool::new(["reset", "high"]).unwrap();
+    let client: BlockingYelpFusionClient = client(&key_pool);
+
+    // both keys get probed, then "reset" is preferred since its quota has reset since
+    assert!(client.all_categories(None).is_ok());
+    assert!(client.all_categories(None).is_ok());
+    assert!(client.all_categories(None).is_ok());
+
+    let stats: Vec<ApiKeyStats> = key_pool.stats();
+    assert_eq!(2, stats[0].requests);
+    assert_eq!(1, stats[1].requests);
+}
+
+#[test]
+fn test_empty_pool_error() {
+    assert!(matches!(
+        ApiKeyPool::new(Vec::<String>::new()),
+        Err(ApiKeyError::EmptyPool)
+    ));
+}
@@ -0,0 +1,29 @@
+use yelp_fusion_rs::endpoints::AutocompleteResponse;
+
+#[test]
+fn test_deserialize_without_businesses() {
+    let autocomplete_response: AutocompleteResponse = serde_json::from_str(
+        r#"{
+            "terms": [{"text": "Delivery"}],
+            "categories": [{"alias": "delis", "title": "Delis"}]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!("Delivery", autocomplete_response.terms[0].text);
+    assert!(autocomplete_response.businesses.is_empty());
+    assert_eq!("delis", autocomplete_response.categories[0].alias);
+}
+
+#[test]
+fn test_deserialize_with_businesses() {
+    let autocomplete_response: AutocompleteResponse = serde_json::from_str(
+        r#"{
+            "terms": [],
+            "businesses": [{"id": "6eDc1A9jYdp6vG0Zqf5bMw", "name": "Delfina"}],
+            "categories": []
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(1, autocomplete_response.businesses.len());
+    assert_eq!("Delfina", autocomplete_response.businesses[0].name);
+}
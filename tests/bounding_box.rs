@@ -0,0 +1,51 @@
+use yelp_fusion_rs::models::{BoundingBox, Coordinates, SearchArea};
+
+#[test]
+fn test_distance_to() {
+    let new_york: Coordinates = Coordinates::new(40.7128, -74.0060);
+    let los_angeles: Coordinates = Coordinates::new(34.0522, -118.2437);
+    let distance: f64 = new_york.distance_to(&los_angeles);
+    assert!((distance - 3_936_000.0).abs() < 5_000.0);
+    assert!(new_york.distance_to(&new_york) < 1.0);
+}
+
+#[test]
+fn test_around_contains_circle() {
+    let center: Coordinates = Coordinates::new(40.0, -74.0);
+    let bounding_box: BoundingBox = BoundingBox::around(center, 10_000);
+    assert!(bounding_box.contains(&center));
+    assert!(bounding_box.contains(&Coordinates::new(40.089, -74.0)));
+    assert!(!bounding_box.contains(&Coordinates::new(40.1, -74.0)));
+
+    let circumradius: f64 = bounding_box.circumradius();
+    assert!(circumradius > 14_000.0 && circumradius < 14_300.0);
+}
+
+#[test]
+fn test_quadrants() {
+    let bounding_box: BoundingBox =
+        BoundingBox::new(Coordinates::new(40.0, -74.0), Coordinates::new(41.0, -73.0));
+    let quadrants: [BoundingBox; 4] = bounding_box.quadrants();
+    for quadrant in &quadrants {
+        assert!(bounding_box.contains(&quadrant.south_west));
+        assert!(bounding_box.contains(&quadrant.north_east));
+        assert!(quadrant.circumradius() < bounding_box.circumradius());
+    }
+    assert!(quadrants[0].contains(&Coordinates::new(40.25, -73.75)));
+    assert!(quadrants[3].contains(&Coordinates::new(40.75, -73.25)));
+}
+
+#[test]
+fn test_search_area_contains() {
+    let circle: SearchArea = SearchArea::Circle {
+        center: Coordinates::new(40.0, -74.0),
+        radius: 10_000,
+    };
+    assert!(circle.contains(&Coordinates::new(40.05, -74.0)));
+    assert!(!circle.contains(&Coordinates::new(40.089, -73.911)));
+    assert!(
+        circle
+            .bounding_box()
+            .contains(&Coordinates::new(40.089, -73.911))
+    );
+}
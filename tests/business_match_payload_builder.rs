@@ -0,0 +1,96 @@
+use yelp_fusion_rs::endpoints::{
+    BusinessMatchPayload, BusinessMatchPayloadBuilder, BusinessMatchPayloadError,
+};
+use yelp_fusion_rs::models::{Coordinates, MatchThreshold};
+
+fn required_fields() -> BusinessMatchPayloadBuilder {
+    let mut builder = BusinessMatchPayloadBuilder::default();
+    builder
+        .name(String::from("Gary Danko"))
+        .address1(String::from("800 N Point St"))
+        .city(String::from("San Francisco"))
+        .state(String::from("CA"))
+        .country(String::from("US"));
+    builder
+}
+
+#[test]
+fn test_only_required_fields() {
+    let business_match_payload_result: Result<BusinessMatchPayload, BusinessMatchPayloadError> =
+        required_fields().build();
+    assert!(business_match_payload_result.is_ok());
+}
+
+#[test]
+fn test_all_fields() {
+    let business_match_payload_result: Result<BusinessMatchPayload, BusinessMatchPayloadError> =
+        required_fields()
+            .address2(String::from("Suite 1"))
+            .address3(String::from("Floor 2"))
+            .coordinates(Coordinates::new(37.805_843, -122.420_21))
+            .phone(String::from("+14157492060"))
+            .zip_code(String::from("94109"))
+            .yelp_business_id(String::from("WavvLdfdP6g8aZTtbBQHTw"))
+            .limit(10)
+            .match_threshold(MatchThreshold::Strict)
+            .build();
+    assert!(business_match_payload_result.is_ok());
+}
+
+#[test]
+fn test_missing_required_field_error() {
+    let business_match_payload_result: Result<BusinessMatchPayload, BusinessMatchPayloadError> =
+        BusinessMatchPayloadBuilder::default()
+            .name(String::from("Gary Danko"))
+            .address1(String::from("800 N Point St"))
+            .city(String::from("San Francisco"))
+            .country(String::from("US"))
+            .build();
+    match business_match_payload_result {
+        Ok(_) => panic!("Returned a BusinessMatchPayload!"),
+        Err(e) => match e {
+            BusinessMatchPayloadError::MissingRequiredField(field) => assert_eq!("state", field),
+            BusinessMatchPayloadError::FieldTooLong { .. } => panic!("Returned FieldTooLong!"),
+            BusinessMatchPayloadError::LimitOutOfRange(_) => panic!("Returned LimitOutOfRange!"),
+        },
+    }
+}
+
+#[test]
+fn test_field_too_long_error() {
+    let business_match_payload_result: Result<BusinessMatchPayload, BusinessMatchPayloadError> =
+        required_fields().name("a".repeat(65)).build();
+    match business_match_payload_result {
+        Ok(_) => panic!("Returned a BusinessMatchPayload!"),
+        Err(e) => match e {
+            BusinessMatchPayloadError::MissingRequiredField(_) => {
+                panic!("Returned MissingRequiredField!")
+            }
+            BusinessMatchPayloadError::FieldTooLong { field, max_length } => {
+                assert_eq!("name", field);
+                assert_eq!(64, max_length);
+            }
+            BusinessMatchPayloadError::LimitOutOfRange(_) => panic!("Returned LimitOutOfRange!"),
+        },
+    }
+}
+
+#[test]
+fn test_limit_out_of_range_error() {
+    for limit in [0, 11] {
+        let business_match_payload_result: Result<BusinessMatchPayload, BusinessMatchPayloadError> =
+            required_fields().limit(limit).build();
+        match business_match_payload_result {
+            Ok(_) => panic!("Returned a BusinessMatchPayload!"),
+            Err(e) => match e {
+                BusinessMatchPayloadError::MissingRequiredField(_) => {
+                    panic!("Returned MissingRequiredField!")
+                }
+                BusinessMatchPayloadError::FieldTooLong { .. } => panic!("Returned FieldTooLong!"),
+                BusinessMatchPayloadError::LimitOutOfRange(out_of_range) => {
+                    assert_eq!(limit, out_of_range);
+                }
+            },
+        }
+    }
+}
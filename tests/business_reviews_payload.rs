@@ -0,0 +1,53 @@
+use yelp_fusion_rs::endpoints::{BusinessReviewsPayload, BusinessReviewsPayloadError};
+use yelp_fusion_rs::models::ReviewSortBy;
+
+#[test]
+fn test_constructor_only_required_fields() {
+    let business_reviews_payload_result: Result<
+        BusinessReviewsPayload,
+        BusinessReviewsPayloadError,
+    > = BusinessReviewsPayload::new(None, None, None, None);
+    assert!(business_reviews_payload_result.is_ok());
+}
+
+#[test]
+fn test_constructor_all_fields() {
+    let business_reviews_payload_result: Result<
+        BusinessReviewsPayload,
+        BusinessReviewsPayloadError,
+    > = BusinessReviewsPayload::new(
+        Some(String::from("en_US")),
+        Some(20),
+        Some(50),
+        Some(ReviewSortBy::Newest),
+    );
+    assert!(business_reviews_payload_result.is_ok());
+}
+
+#[test]
+fn test_constructor_limit_too_large_error() {
+    let business_reviews_payload_result: Result<
+        BusinessReviewsPayload,
+        BusinessReviewsPayloadError,
+    > = BusinessReviewsPayload::new(None, None, Some(69), None);
+    match business_reviews_payload_result {
+        Ok(_) => panic!("Returned a BusinessReviewsPayload!"),
+        Err(e) => match e {
+            BusinessReviewsPayloadError::LimitTooLarge(limit) => assert_eq!(69, limit),
+        },
+    }
+}
+
+#[test]
+fn test_builder_all_fields() {
+    let business_reviews_payload_result: Result<
+        BusinessReviewsPayload,
+        BusinessReviewsPayloadError,
+    > = BusinessReviewsPayload::builder()
+        .locale(String::from("en_US"))
+        .offset(20)
+        .limit(50)
+        .sort_by(ReviewSortBy::default())
+        .build();
+    assert!(business_reviews_payload_result.is_ok());
+}
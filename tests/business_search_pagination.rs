@@ -0,0 +1,89 @@
+#![cfg(feature = "reqwest")]
+
+pub mod common;
+
+use common::{MockResponse, business_json, spawn_server};
+use futures::StreamExt;
+use std::collections::HashSet;
+use yelp_fusion_rs::endpoints::BusinessSearchPayload;
+use yelp_fusion_rs::error::Error;
+use yelp_fusion_rs::models::{Business, Coordinates};
+use yelp_fusion_rs::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+
+/// 120 results, where the second page overlaps the first one by 5 businesses.
+fn respond(request: &str) -> MockResponse {
+    let ids: Vec<usize> = if request.contains("offset=0&") || request.contains("offset=0 ") {
+        (0..50).collect()
+    } else if request.contains("offset=50") {
+        (45..95).collect()
+    } else if request.contains("offset=100") {
+        (100..120).collect()
+    } else {
+        Vec::new()
+    };
+    let businesses: Vec<String> = ids
+        .iter()
+        .map(|id| business_json(&id.to_string(), Coordinates::new(0.0, 0.0)))
+        .collect();
+    (
+        "200 OK",
+        String::new(),
+        format!(r#"{{"total":120,"businesses":[{}]}}"#, businesses.join(",")),
+    )
+}
+
+fn payload() -> BusinessSearchPayload {
+    BusinessSearchPayload::builder()
+        .location("New York City".to_string())
+        .offset(0)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_iter_pages_and_dedupes() {
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(spawn_server(respond))
+        .build()
+        .unwrap();
+
+    let businesses: Vec<Business> = client
+        .business_search_iter(&payload())
+        .collect::<Result<Vec<Business>, Error>>()
+        .unwrap();
+    assert_eq!(115, businesses.len());
+    let ids: HashSet<String> = businesses.into_iter().map(|business| business.id).collect();
+    assert_eq!(115, ids.len());
+}
+
+#[test]
+fn test_iter_early_termination() {
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(spawn_server(respond))
+        .build()
+        .unwrap();
+
+    let businesses: Vec<Business> = client
+        .business_search_iter(&payload())
+        .take(3)
+        .collect::<Result<Vec<Business>, Error>>()
+        .unwrap();
+    let ids: Vec<&str> = businesses
+        .iter()
+        .map(|business| business.id.as_str())
+        .collect();
+    assert_eq!(vec!["0", "1", "2"], ids);
+}
+
+#[tokio::test]
+async fn test_stream_pages_and_dedupes() {
+    let client: YelpFusionClient = YelpFusionClient::builder("api_key")
+        .base_url(spawn_server(respond))
+        .build()
+        .unwrap();
+
+    let businesses: Vec<Result<Business, Error>> =
+        client.business_search_stream(&payload()).collect().await;
+    assert_eq!(115, businesses.len());
+    assert!(businesses.iter().all(Result::is_ok));
+}
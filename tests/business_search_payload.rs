@@ -165,3 +165,60 @@ fn test_constructor_both_open_now_and_open_at_set_error() {
         },
     }
 }
+
+fn payload_with_sets() -> BusinessSearchPayload {
+    BusinessSearchPayload::builder()
+        .term(String::from("restaurants"))
+        .location(String::from("New York City"))
+        .price(HashSet::from([
+            PriceType::FourDollar,
+            PriceType::OneDollar,
+            PriceType::ThreeDollar,
+        ]))
+        .attributes(HashSet::from([
+            Attribute::Reservation,
+            Attribute::Deals,
+            Attribute::HotAndNew,
+        ]))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_to_query_params_sorts_sets() {
+    let payload: BusinessSearchPayload = payload_with_sets();
+    let query_params: Vec<(&str, String)> = payload.to_query_params();
+    assert!(query_params.contains(&("price", String::from("1,3,4"))));
+    assert!(query_params.contains(&("attributes", String::from("deals,hot_and_new,reservation"))));
+}
+
+#[test]
+fn test_cache_key_is_stable() {
+    let payload: BusinessSearchPayload = payload_with_sets();
+    assert_eq!(
+        "/businesses/search?attributes=deals,hot_and_new,reservation&location=New York City&price=1,3,4&term=restaurants",
+        payload.cache_key()
+    );
+    assert_eq!(payload.stable_hash(), payload_with_sets().stable_hash());
+    assert_ne!(
+        payload.stable_hash(),
+        BusinessSearchPayload::builder()
+            .location(String::from("New York City"))
+            .build()
+            .unwrap()
+            .stable_hash()
+    );
+}
+
+#[test]
+fn test_to_url() {
+    let url: String = payload_with_sets()
+        .to_url("https://api.yelp.com/v3/")
+        .unwrap()
+        .to_string();
+    assert_eq!(
+        "https://api.yelp.com/v3/businesses/search?term=restaurants&location=New+York+City&price=1%2C3%2C4&attributes=deals%2Chot_and_new%2Creservation",
+        url
+    );
+    assert!(payload_with_sets().to_url("not a url").is_err());
+}
@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+use yelp_fusion_rs::endpoints::{
+    BusinessSearchPayload, BusinessSearchPayloadError, BusinessSearchPayloadParseError,
+};
+use yelp_fusion_rs::models::{Attribute, Coordinates, PriceType, SortBy};
+
+#[test]
+fn test_from_query_params_round_trip() {
+    let payload: BusinessSearchPayload = BusinessSearchPayload::builder()
+        .term(String::from("restaurants"))
+        .coordinates(Coordinates::new(37.772_484, -122.396_68))
+        .radius(1609)
+        .categories(vec![String::from("mexican"), String::from("sandwiches")])
+        .locale(String::from("en_US"))
+        .limit(50)
+        .offset(100)
+        .sort_by(SortBy::Rating)
+        .price(HashSet::from([
+            PriceType::OneDollar,
+            PriceType::ThreeDollar,
+        ]))
+        .open_at(1_700_000_000)
+        .attributes(HashSet::from([Attribute::HotAndNew, Attribute::Deals]))
+        .build()
+        .unwrap();
+
+    let parsed: BusinessSearchPayload =
+        BusinessSearchPayload::from_query_params(payload.to_query_params()).unwrap();
+    assert_eq!(payload.cache_key(), parsed.cache_key());
+}
+
+#[test]
+fn test_from_str_query_string() {
+    let payload: BusinessSearchPayload =
+        "?term=pizza&location=New+York+City&price=1%2C2&open_now=true&attributes=deals"
+            .parse()
+            .unwrap();
+    assert_eq!(Some(String::from("pizza")), payload.term);
+    assert_eq!(Some(String::from("New York City")), payload.location);
+    assert_eq!(
+        Some(HashSet::from([PriceType::OneDollar, PriceType::TwoDollar])),
+        payload.price
+    );
+    assert_eq!(Some(true), payload.open_now);
+    assert_eq!(Some(HashSet::from([Attribute::Deals])), payload.attributes);
+}
+
+#[test]
+fn test_from_str_api_url() {
+    let payload: BusinessSearchPayload = BusinessSearchPayload::builder()
+        .term(String::from("coffee"))
+        .location(String::from("Seattle, WA"))
+        .build()
+        .unwrap();
+    let url: String = payload
+        .to_url("https://api.yelp.com/v3")
+        .unwrap()
+        .to_string();
+    let parsed: BusinessSearchPayload = url.parse().unwrap();
+    assert_eq!(payload.cache_key(), parsed.cache_key());
+}
+
+#[test]
+fn test_from_str_yelp_url() {
+    let payload: BusinessSearchPayload = "https://www.yelp.com/search?find_desc=Pizza&find_loc=San+Francisco%2C+CA&cflt=pizza&sortby=rating&attrs=RestaurantsPriceRange2.1,RestaurantsPriceRange2.2,NewBusiness,Unmapped&ns=1"
+        .parse()
+        .unwrap();
+    assert_eq!(Some(String::from("Pizza")), payload.term);
+    assert_eq!(Some(String::from("San Francisco, CA")), payload.location);
+    assert_eq!(Some(vec![String::from("pizza")]), payload.categories);
+    assert!(matches!(payload.sort_by, Some(SortBy::Rating)));
+    assert_eq!(
+        Some(HashSet::from([PriceType::OneDollar, PriceType::TwoDollar])),
+        payload.price
+    );
+    assert_eq!(
+        Some(HashSet::from([Attribute::HotAndNew])),
+        payload.attributes
+    );
+}
+
+#[test]
+fn test_from_str_errors() {
+    match "term=pizza&unknown=1".parse::<BusinessSearchPayload>() {
+        Ok(_) => panic!("Returned a BusinessSearchPayload!"),
+        Err(BusinessSearchPayloadParseError::UnknownQueryParam(key)) => assert_eq!("unknown", key),
+        Err(e) => panic!("Returned {e}!"),
+    }
+    match "price=5".parse::<BusinessSearchPayload>() {
+        Ok(_) => panic!("Returned a BusinessSearchPayload!"),
+        Err(BusinessSearchPayloadParseError::InvalidQueryParam { key, .. }) => {
+            assert_eq!("price", key);
+        }
+        Err(e) => panic!("Returned {e}!"),
+    }
+    match "term=pizza&term=sushi".parse::<BusinessSearchPayload>() {
+        Ok(_) => panic!("Returned a BusinessSearchPayload!"),
+        Err(BusinessSearchPayloadParseError::InvalidQueryParam { key, value }) => {
+            assert_eq!("term", key);
+            assert_eq!("sushi", value);
+        }
+        Err(e) => panic!("Returned {e}!"),
+    }
+    match "latitude=37.7".parse::<BusinessSearchPayload>() {
+        Ok(_) => panic!("Returned a BusinessSearchPayload!"),
+        Err(BusinessSearchPayloadParseError::IncompleteCoordinates) => (),
+        Err(e) => panic!("Returned {e}!"),
+    }
+    match "limit=51".parse::<BusinessSearchPayload>() {
+        Ok(_) => panic!("Returned a BusinessSearchPayload!"),
+        Err(BusinessSearchPayloadParseError::InvalidPayload(
+            BusinessSearchPayloadError::LimitTooLarge(51),
+        )) => (),
+        Err(e) => panic!("Returned {e}!"),
+    }
+}
+
+#[test]
+fn test_from_yelp_url_rejects_other_hosts() {
+    for url in [
+        "https://example.com/search?find_desc=Pizza",
+        "https://notyelp.com/search?find_desc=Pizza",
+        "https://api.yelp.com/v3/businesses/search?term=Pizza",
+    ] {
+        match BusinessSearchPayload::from_yelp_url(url) {
+            Ok(_) => panic!("Returned a BusinessSearchPayload!"),
+            Err(BusinessSearchPayloadParseError::InvalidUrl(_)) => (),
+            Err(e) => panic!("Returned {e}!"),
+        }
+    }
+    assert!(
+        BusinessSearchPayload::from_yelp_url("https://yelp.com/search?find_desc=Pizza").is_ok()
+    );
+}
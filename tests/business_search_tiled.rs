@@ -0,0 +1,120 @@
+#![cfg(feature = "reqwest")]
+
+pub mod common;
+
+use common::{MockResponse, business_json, spawn_server};
+use std::collections::HashSet;
+use yelp_fusion_rs::endpoints::{BusinessSearchPayload, BusinessSearchTiledResponse};
+use yelp_fusion_rs::models::{BoundingBox, Business, Coordinates, SearchArea};
+use yelp_fusion_rs::yelp_fusion::BlockingYelpFusionClient;
+
+fn query_param<T: std::str::FromStr>(request: &str, key: &str) -> T {
+    let start: usize = request.find(&format!("{key}=")).unwrap() + key.len() + 1;
+    let value: &str = request[start..].split(['&', ' ']).next().unwrap();
+    value.parse().ok().unwrap()
+}
+
+/// Searches over 15 km claim more results than can be paged through; smaller ones return a
+/// business at their center, one shared by every search and one outside of the area.
+fn respond(request: &str) -> MockResponse {
+    let radius: usize = query_param(request, "radius");
+    let latitude: f32 = query_param(request, "latitude");
+    let longitude: f32 = query_param(request, "longitude");
+
+    let (total, businesses): (usize, Vec<String>) = if radius > 15_000 {
+        (
+            2_000,
+            vec![business_json(
+                "crowded",
+                Coordinates::new(latitude, longitude),
+            )],
+        )
+    } else {
+        (
+            3,
+            vec![
+                business_json(
+                    &format!("{latitude},{longitude}"),
+                    Coordinates::new(latitude, longitude),
+                ),
+                business_json("shared", Coordinates::new(40.3, -73.7)),
+                business_json("outside", Coordinates::new(0.0, 0.0)),
+            ],
+        )
+    };
+    (
+        "200 OK",
+        String::new(),
+        format!(
+            r#"{{"total":{total},"businesses":[{}]}}"#,
+            businesses.join(",")
+        ),
+    )
+}
+
+#[test]
+fn test_tiled_search() {
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(spawn_server(respond))
+        .build()
+        .unwrap();
+    let payload: BusinessSearchPayload = BusinessSearchPayload::builder()
+        .term("restaurants".to_string())
+        .build()
+        .unwrap();
+    let area: SearchArea = SearchArea::BoundingBox(BoundingBox::new(
+        Coordinates::new(40.0, -74.0),
+        Coordinates::new(40.6, -73.4),
+    ));
+
+    // the ~42 km area is split into 4 tiles, which are too crowded and split into 16 tiles
+    let response: BusinessSearchTiledResponse =
+        client.business_search_tiled(&payload, &area).unwrap();
+    let businesses: Vec<Business> = response.businesses;
+    let ids: HashSet<&str> = businesses
+        .iter()
+        .map(|business| business.id.as_str())
+        .collect();
+    assert_eq!(17, businesses.len());
+    assert_eq!(17, ids.len());
+    assert!(ids.contains("shared"));
+    assert!(!ids.contains("crowded"));
+    assert!(!ids.contains("outside"));
+    assert!(response.truncated_tiles.is_empty());
+}
+
+#[test]
+fn test_tiled_search_reports_truncated_tiles() {
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(spawn_server(|request| {
+            let latitude: f32 = query_param(request, "latitude");
+            let longitude: f32 = query_param(request, "longitude");
+            (
+                "200 OK",
+                String::new(),
+                format!(
+                    r#"{{"total":2000,"businesses":[{}]}}"#,
+                    business_json(
+                        &format!("{latitude},{longitude}"),
+                        Coordinates::new(latitude, longitude)
+                    )
+                ),
+            )
+        }))
+        .build()
+        .unwrap();
+    let payload: BusinessSearchPayload = BusinessSearchPayload::builder()
+        .term("restaurants".to_string())
+        .build()
+        .unwrap();
+    let area: SearchArea = SearchArea::BoundingBox(BoundingBox::new(
+        Coordinates::new(40.0, -74.0),
+        Coordinates::new(40.004, -73.996),
+    ));
+
+    // the ~280 m area is split once, into 4 tiles that are still crowded but can't be split
+    let response: BusinessSearchTiledResponse =
+        client.business_search_tiled(&payload, &area).unwrap();
+    assert_eq!(4, response.businesses.len());
+    assert_eq!(4, response.truncated_tiles.len());
+}
@@ -0,0 +1,58 @@
+use yelp_fusion_rs::models::{Category, CategoryTree};
+
+fn category(alias: &str, parent_aliases: &[&str]) -> Category {
+    Category {
+        alias: String::from(alias),
+        title: String::from(alias),
+        parent_aliases: parent_aliases.iter().map(ToString::to_string).collect(),
+        country_whitelist: vec![],
+        country_blacklist: vec![],
+    }
+}
+
+fn category_tree() -> CategoryTree {
+    CategoryTree::new(vec![
+        category("food", &[]),
+        category("restaurants", &[]),
+        category("bakeries", &["food"]),
+        category("coffee", &["food"]),
+        category("coffeeroasteries", &["coffee"]),
+        category("cafes", &["restaurants", "food"]),
+    ])
+}
+
+#[test]
+fn test_roots() {
+    let category_tree: CategoryTree = category_tree();
+    let roots: Vec<&str> = category_tree
+        .roots()
+        .iter()
+        .map(|category| category.alias.as_str())
+        .collect();
+    assert_eq!(vec!["food", "restaurants"], roots);
+}
+
+#[test]
+fn test_children() {
+    let category_tree: CategoryTree = category_tree();
+    let children: Vec<&str> = category_tree
+        .children("food")
+        .iter()
+        .map(|category| category.alias.as_str())
+        .collect();
+    assert_eq!(vec!["bakeries", "cafes", "coffee"], children);
+}
+
+#[test]
+fn test_descendant_aliases() {
+    let category_tree: CategoryTree = category_tree();
+    assert_eq!(
+        vec!["food", "bakeries", "cafes", "coffee", "coffeeroasteries"],
+        category_tree.descendant_aliases("food")
+    );
+    assert_eq!(
+        vec!["coffeeroasteries"],
+        category_tree.descendant_aliases("coffeeroasteries")
+    );
+    assert!(category_tree.descendant_aliases("unknown").is_empty());
+}
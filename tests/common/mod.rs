@@ -0,0 +1,62 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use yelp_fusion_rs::models::Coordinates;
+
+/// Response of a mock server: status line, extra header lines and body.
+pub type MockResponse = (&'static str, String, String);
+
+/// Serves every request on a local port with the response `handler` returns for the request's
+/// head (request line and headers, lowercased), returning the server's base URL.
+///
+/// Every connection is served on its own thread, so concurrent requests are handled concurrently.
+///
+/// # Panics
+///
+/// Panics if no local port could be bound.
+pub fn spawn_server<F: Fn(&str) -> MockResponse + Send + Sync + 'static>(handler: F) -> String {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url: String = format!("http://{}", listener.local_addr().unwrap());
+    let handler: Arc<F> = Arc::new(handler);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let stream: TcpStream = stream.unwrap();
+            let handler: Arc<F> = Arc::clone(&handler);
+            thread::spawn(move || respond(stream, handler.as_ref()));
+        }
+    });
+    base_url
+}
+
+fn respond<F: Fn(&str) -> MockResponse>(mut stream: TcpStream, handler: &F) {
+    let mut request: Vec<u8> = Vec::new();
+    let mut buffer: [u8; 1024] = [0; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        let read: usize = stream.read(&mut buffer).unwrap();
+        if read == 0 {
+            return;
+        }
+        request.extend_from_slice(&buffer[..read]);
+    }
+
+    let (status, headers, body): MockResponse =
+        handler(&String::from_utf8_lossy(&request).to_lowercase());
+    let response: String = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).unwrap();
+}
+
+/// Minimal Business Search result with the given ID, located at `coordinates`.
+#[must_use]
+pub fn business_json(id: &str, coordinates: Coordinates) -> String {
+    let Coordinates {
+        latitude,
+        longitude,
+    } = coordinates;
+    format!(
+        r#"{{"id":"{id}","alias":"{id}","name":"{id}","image_url":"","is_closed":false,"url":"","review_count":0,"categories":[],"rating":5.0,"coordinates":{{"latitude":{latitude},"longitude":{longitude}}},"transactions":[],"price":"$","location":{{"address1":"","city":"","zip_code":"","country":"","state":"","display_address":[]}},"phone":"","display_phone":""}}"#
+    )
+}
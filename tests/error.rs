@@ -0,0 +1,164 @@
+pub mod common;
+
+use bytes::Bytes;
+#[cfg(feature = "reqwest")]
+use common::spawn_server;
+use http::StatusCode;
+use std::time::Duration;
+use yelp_fusion_rs::error::{ApiErrorCode, ApiErrorResponse, Error};
+use yelp_fusion_rs::response_meta::ResponseMeta;
+#[cfg(feature = "reqwest")]
+use yelp_fusion_rs::yelp_fusion::BlockingYelpFusionClient;
+
+fn request_failed(body: &str, status_code: StatusCode) -> Error {
+    let error_response: ApiErrorResponse = serde_json::from_str(body).unwrap();
+    Error::RequestFailed {
+        error_response,
+        status_code,
+        meta: None,
+    }
+}
+
+fn response_meta(status_code: StatusCode) -> ResponseMeta {
+    ResponseMeta {
+        status_code,
+        request_id: None,
+        rate_limit: None,
+        latency: Duration::ZERO,
+        cache_hit: false,
+    }
+}
+
+#[test]
+fn test_api_error_code_known() {
+    let error_response: ApiErrorResponse = serde_json::from_str(
+        r#"{"error": {"code": "TOKEN_INVALID", "description": "Invalid access token or authorization header."}}"#,
+    )
+    .unwrap();
+    assert_eq!(ApiErrorCode::TokenInvalid, error_response.error.code);
+}
+
+#[test]
+fn test_api_error_code_unknown_round_trip() {
+    let error_response: ApiErrorResponse = serde_json::from_str(
+        r#"{"error": {"code": "SOMETHING_NEW", "description": "Something new happened."}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ApiErrorCode::Unknown(String::from("SOMETHING_NEW")),
+        error_response.error.code
+    );
+    assert!(
+        serde_json::to_string(&error_response)
+            .unwrap()
+            .contains(r#""code":"SOMETHING_NEW""#)
+    );
+}
+
+#[test]
+fn test_api_error_validation_details() {
+    let error_response: ApiErrorResponse = serde_json::from_str(
+        r#"{"error": {"code": "VALIDATION_ERROR", "description": "'abc' is not of type 'integer'", "field": "limit", "instance": "abc"}}"#,
+    )
+    .unwrap();
+    assert_eq!(ApiErrorCode::ValidationError, error_response.error.code);
+    assert_eq!(Some(String::from("limit")), error_response.error.field);
+    assert_eq!(
+        Some(serde_json::Value::from("abc")),
+        error_response.error.instance
+    );
+}
+
+#[test]
+fn test_classifiers() {
+    let too_many_requests: Error = request_failed(
+        r#"{"error": {"code": "TOO_MANY_REQUESTS_PER_SECOND", "description": ""}}"#,
+        StatusCode::TOO_MANY_REQUESTS,
+    );
+    assert!(too_many_requests.is_retryable());
+    assert!(!too_many_requests.is_auth_error());
+    assert!(!too_many_requests.is_quota_error());
+
+    let token_invalid: Error = request_failed(
+        r#"{"error": {"code": "TOKEN_INVALID", "description": ""}}"#,
+        StatusCode::UNAUTHORIZED,
+    );
+    assert!(!token_invalid.is_retryable());
+    assert!(token_invalid.is_auth_error());
+
+    let access_limit_reached: Error = request_failed(
+        r#"{"error": {"code": "ACCESS_LIMIT_REACHED", "description": ""}}"#,
+        StatusCode::TOO_MANY_REQUESTS,
+    );
+    assert!(!access_limit_reached.is_retryable());
+    assert!(access_limit_reached.is_quota_error());
+
+    let unknown_bad_gateway: Error = request_failed(
+        r#"{"error": {"code": "BAD_GATEWAY", "description": ""}}"#,
+        StatusCode::BAD_GATEWAY,
+    );
+    assert!(unknown_bad_gateway.is_retryable());
+
+    let validation_error: Error = request_failed(
+        r#"{"error": {"code": "VALIDATION_ERROR", "description": ""}}"#,
+        StatusCode::BAD_REQUEST,
+    );
+    assert!(!validation_error.is_retryable());
+}
+
+#[test]
+fn test_unexpected_response() {
+    let bad_gateway: Error = Error::UnexpectedResponse {
+        status_code: StatusCode::BAD_GATEWAY,
+        headers: Box::default(),
+        body: Bytes::from_static(b"<html>502 Bad Gateway</html>"),
+        meta: Box::new(response_meta(StatusCode::BAD_GATEWAY)),
+    };
+    assert!(bad_gateway.is_retryable());
+    assert_eq!(
+        "[502 Bad Gateway] unexpected response (unknown content type, 28 bytes): <html>502 Bad Gateway</html>",
+        bad_gateway.to_string()
+    );
+
+    let not_found: Error = Error::UnexpectedResponse {
+        status_code: StatusCode::NOT_FOUND,
+        headers: Box::default(),
+        body: Bytes::new(),
+        meta: Box::new(response_meta(StatusCode::NOT_FOUND)),
+    };
+    assert!(!not_found.is_retryable());
+}
+
+#[cfg(feature = "reqwest")]
+#[test]
+fn test_unexpected_response_from_server() {
+    let base_url: String = spawn_server(|_| {
+        (
+            "503 Service Unavailable",
+            String::new(),
+            format!("<html>{}</html>", "a".repeat(1000)),
+        )
+    });
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(base_url)
+        .build()
+        .unwrap();
+
+    let error: Error = client.all_categories(None).unwrap_err();
+    assert_eq!(
+        format!(
+            "[503 Service Unavailable] unexpected response (application/json, 1013 bytes): <html>{}...",
+            "a".repeat(194)
+        ),
+        error.to_string()
+    );
+    match error {
+        Error::UnexpectedResponse {
+            status_code, body, ..
+        } => {
+            assert_eq!(StatusCode::SERVICE_UNAVAILABLE, status_code);
+            assert_eq!(1013, body.len());
+        }
+        _ => panic!("Returned another error!"),
+    }
+}
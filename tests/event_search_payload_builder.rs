@@ -0,0 +1,91 @@
+use yelp_fusion_rs::endpoints::{
+    EventSearchPayload, EventSearchPayloadBuilder, EventSearchPayloadError,
+};
+use yelp_fusion_rs::models::{Coordinates, EventSortBy, EventSortOn};
+
+#[test]
+fn test_only_required_fields() {
+    let event_search_payload_result: Result<EventSearchPayload, EventSearchPayloadError> =
+        EventSearchPayloadBuilder::default().build();
+    assert!(event_search_payload_result.is_ok());
+}
+
+#[test]
+fn test_all_fields() {
+    // Some fields aren't set due to certain pairs not being allowed to be set at the same time.
+    let event_search_payload_result: Result<EventSearchPayload, EventSearchPayloadError> =
+        EventSearchPayloadBuilder::default()
+            .locale(String::from("en_US"))
+            .offset(0)
+            .limit(50)
+            .sort_by(EventSortBy::Asc)
+            .sort_on(EventSortOn::TimeStart)
+            .start_date(1_662_917_069)
+            .end_date(1_663_917_069)
+            .categories(vec![String::from("music"), String::from("festivals")])
+            .is_free(true)
+            .coordinates(Coordinates::new(37.772_484, -122.396_68))
+            .radius(1609)
+            .excluded_events(vec![String::from("san-francisco-fun-event")])
+            .build();
+    assert!(event_search_payload_result.is_ok());
+}
+
+#[test]
+fn test_all_fields_reciprocal() {
+    // This tests the builder fields that weren't used in the earlier test "test_all_fields".
+    let event_search_payload_result: Result<EventSearchPayload, EventSearchPayloadError> =
+        EventSearchPayloadBuilder::default()
+            .location(String::from("Fergus, Ontario"))
+            .build();
+    assert!(event_search_payload_result.is_ok());
+}
+
+#[test]
+fn test_location_and_coordinates_set_error() {
+    let event_search_payload_result: Result<EventSearchPayload, EventSearchPayloadError> =
+        EventSearchPayload::builder()
+            .location(String::from("Chicago, IL"))
+            .coordinates(Coordinates::new(37.772_484, -122.396_68))
+            .build();
+    assert!(matches!(
+        event_search_payload_result,
+        Err(EventSearchPayloadError::BothLocationAndLatLongSet)
+    ));
+}
+
+#[test]
+fn test_radius_too_large_error() {
+    let event_search_payload_result: Result<EventSearchPayload, EventSearchPayloadError> =
+        EventSearchPayload::builder().radius(69_420).build();
+    assert!(matches!(
+        event_search_payload_result,
+        Err(EventSearchPayloadError::RadiusTooLarge(69_420))
+    ));
+}
+
+#[test]
+fn test_limit_too_large_error() {
+    let event_search_payload_result: Result<EventSearchPayload, EventSearchPayloadError> =
+        EventSearchPayload::builder().limit(69).build();
+    assert!(matches!(
+        event_search_payload_result,
+        Err(EventSearchPayloadError::LimitTooLarge(69))
+    ));
+}
+
+#[test]
+fn test_end_date_before_start_date_error() {
+    let event_search_payload_result: Result<EventSearchPayload, EventSearchPayloadError> =
+        EventSearchPayload::builder()
+            .start_date(1_663_917_069)
+            .end_date(1_662_917_069)
+            .build();
+    assert!(matches!(
+        event_search_payload_result,
+        Err(EventSearchPayloadError::EndDateBeforeStartDate {
+            start_date: 1_663_917_069,
+            end_date: 1_662_917_069,
+        })
+    ));
+}
@@ -0,0 +1,52 @@
+use yelp_fusion_rs::error::{ApiErrorCode, Error};
+use yelp_fusion_rs::graphql::{GraphQlBusiness, GraphQlResponse};
+
+#[test]
+fn test_into_result_data() {
+    let graphql_response: GraphQlResponse<GraphQlBusiness> = serde_json::from_str(
+        r#"{"data": {"id": "WavvLdfdP6g8aZTtbBQHTw", "name": "Gary Danko", "photos": []}}"#,
+    )
+    .unwrap();
+    let business: GraphQlBusiness = graphql_response.into_result().unwrap();
+    assert_eq!("WavvLdfdP6g8aZTtbBQHTw", business.id);
+    assert_eq!(Some(String::from("Gary Danko")), business.name);
+}
+
+#[test]
+fn test_into_result_errors() {
+    let graphql_response: GraphQlResponse<GraphQlBusiness> = serde_json::from_str(
+        r#"{
+            "data": null,
+            "errors": [
+                {
+                    "message": "Business not found",
+                    "path": ["business"],
+                    "extensions": {"code": "BUSINESS_NOT_FOUND"}
+                },
+                {"message": "Something else went wrong"}
+            ]
+        }"#,
+    )
+    .unwrap();
+    match graphql_response.into_result() {
+        Ok(_) => panic!("Returned a GraphQlBusiness!"),
+        Err(Error::RequestFailed { error_response, .. }) => {
+            assert_eq!(ApiErrorCode::BusinessNotFound, error_response.error.code);
+            assert_eq!(
+                "Business not found; Something else went wrong",
+                error_response.error.description
+            );
+        }
+        Err(e) => panic!("Returned {e:?}!"),
+    }
+}
+
+#[test]
+fn test_into_result_no_data() {
+    let graphql_response: GraphQlResponse<GraphQlBusiness> =
+        serde_json::from_str(r#"{"data": null}"#).unwrap();
+    assert!(matches!(
+        graphql_response.into_result(),
+        Err(Error::RequestFailed { .. })
+    ));
+}
@@ -0,0 +1,47 @@
+use yelp_fusion_rs::endpoints::{PhoneSearchPayload, PhoneSearchPayloadError};
+
+#[test]
+fn test_constructor_only_required_fields() {
+    let phone_search_payload_result: Result<PhoneSearchPayload, PhoneSearchPayloadError> =
+        PhoneSearchPayload::new(String::from("+14159083801"), None);
+    assert!(phone_search_payload_result.is_ok());
+}
+
+#[test]
+fn test_constructor_all_fields() {
+    let phone_search_payload_result: Result<PhoneSearchPayload, PhoneSearchPayloadError> =
+        PhoneSearchPayload::new(String::from("+14159083801"), Some(String::from("en_US")));
+    assert!(phone_search_payload_result.is_ok());
+}
+
+#[test]
+fn test_constructor_missing_plus_prefix_error() {
+    let phone_search_payload_result: Result<PhoneSearchPayload, PhoneSearchPayloadError> =
+        PhoneSearchPayload::new(String::from("14159083801"), None);
+    match phone_search_payload_result {
+        Ok(_) => panic!("Returned a PhoneSearchPayload!"),
+        Err(e) => match e {
+            PhoneSearchPayloadError::MissingPlusPrefix(phone) => assert_eq!("14159083801", phone),
+            PhoneSearchPayloadError::InvalidPhoneNumber(_) => {
+                panic!("Returned InvalidPhoneNumber!")
+            }
+        },
+    }
+}
+
+#[test]
+fn test_constructor_invalid_phone_number_error() {
+    for phone in ["+", "+04159083801", "+1415-908-3801", "+1234567890123456"] {
+        let phone_search_payload_result: Result<PhoneSearchPayload, PhoneSearchPayloadError> =
+            PhoneSearchPayload::new(String::from(phone), None);
+        match phone_search_payload_result {
+            Ok(_) => panic!("Returned a PhoneSearchPayload!"),
+            Err(e) => match e {
+                PhoneSearchPayloadError::MissingPlusPrefix(_) => {
+                    panic!("Returned MissingPlusPrefix!")
+                }
+                PhoneSearchPayloadError::InvalidPhoneNumber(invalid) => assert_eq!(phone, invalid),
+            },
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use http::header::{HeaderMap, HeaderValue};
+use yelp_fusion_rs::response_meta::RateLimit;
+
+#[test]
+fn test_from_headers() {
+    let mut headers: HeaderMap = HeaderMap::new();
+    headers.insert("RateLimit-DailyLimit", HeaderValue::from_static("5000"));
+    headers.insert("RateLimit-Remaining", HeaderValue::from_static("4321"));
+    headers.insert(
+        "RateLimit-ResetTime",
+        HeaderValue::from_static("2026-10-19T00:00:00+00:00"),
+    );
+    assert_eq!(
+        Some(RateLimit {
+            daily_limit: Some(5000),
+            remaining: Some(4321),
+            reset_time: Some(String::from("2026-10-19T00:00:00+00:00")),
+        }),
+        RateLimit::from_headers(&headers)
+    );
+}
+
+#[test]
+fn test_from_headers_missing() {
+    assert_eq!(None, RateLimit::from_headers(&HeaderMap::new()));
+}
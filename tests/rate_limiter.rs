@@ -0,0 +1,41 @@
+use std::num::NonZeroU32;
+use std::time::Duration;
+use yelp_fusion_rs::error::Error;
+use yelp_fusion_rs::rate_limiter::RateLimiter;
+
+#[test]
+fn test_burst_then_throttle() {
+    let rate_limiter: RateLimiter = RateLimiter::new(NonZeroU32::new(5).unwrap());
+    for _ in 0..5 {
+        assert_eq!(Duration::ZERO, rate_limiter.acquire().unwrap());
+    }
+    let wait: Duration = rate_limiter.acquire().unwrap();
+    assert!(wait > Duration::ZERO);
+    assert!(wait <= Duration::from_millis(200));
+}
+
+#[test]
+fn test_daily_quota_exhausted() {
+    let rate_limiter: RateLimiter =
+        RateLimiter::new(NonZeroU32::new(100).unwrap()).with_daily_quota(2);
+    assert_eq!(Some(2), rate_limiter.daily_remaining());
+    assert!(rate_limiter.acquire().is_ok());
+    assert!(rate_limiter.acquire().is_ok());
+    assert_eq!(Some(0), rate_limiter.daily_remaining());
+    match rate_limiter.acquire() {
+        Ok(_) => panic!("Returned a wait duration!"),
+        Err(e) => {
+            assert!(e.is_quota_error());
+            assert!(matches!(e, Error::LocalQuotaExhausted { daily_quota: 2 }));
+        }
+    }
+}
+
+#[test]
+fn test_clones_share_budget() {
+    let rate_limiter: RateLimiter =
+        RateLimiter::new(NonZeroU32::new(100).unwrap()).with_daily_quota(1);
+    let clone: RateLimiter = rate_limiter.clone();
+    assert!(rate_limiter.acquire().is_ok());
+    assert!(clone.acquire().is_err());
+}
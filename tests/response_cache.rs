@@ -0,0 +1,240 @@
+pub mod common;
+
+use bytes::Bytes;
+#[cfg(feature = "reqwest")]
+use common::{MockResponse, spawn_server};
+use std::collections::HashSet;
+use std::env;
+#[cfg(feature = "reqwest")]
+use std::sync::Arc;
+#[cfg(feature = "reqwest")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use yelp_fusion_rs::cache::{CacheBackend, DiskCache, MemoryCache, ResponseCache};
+use yelp_fusion_rs::endpoints::{BusinessSearchPayload, Endpoint};
+use yelp_fusion_rs::models::{Attribute, PriceType};
+#[cfg(feature = "reqwest")]
+use yelp_fusion_rs::response_meta::ResponseMeta;
+use yelp_fusion_rs::yelp_fusion::BASE_URL;
+#[cfg(feature = "reqwest")]
+use yelp_fusion_rs::yelp_fusion::BlockingYelpFusionClient;
+
+#[test]
+fn test_key_is_canonical() {
+    let key: String = ResponseCache::key(
+        BASE_URL,
+        "/businesses/search",
+        &[
+            ("term", "food".to_string()),
+            ("location", "NYC".to_string()),
+        ],
+    );
+    assert_eq!(
+        key,
+        ResponseCache::key(
+            BASE_URL,
+            "/businesses/search",
+            &[
+                ("location", "NYC".to_string()),
+                ("term", "food".to_string())
+            ],
+        )
+    );
+    assert_ne!(
+        ResponseCache::key(
+            BASE_URL,
+            "/businesses/search",
+            &[("term", "a&b=c".to_string())]
+        ),
+        ResponseCache::key(
+            BASE_URL,
+            "/businesses/search",
+            &[("term", "a".to_string()), ("b", "c".to_string())]
+        )
+    );
+    assert_ne!(
+        ResponseCache::key(BASE_URL, "/categories", &[]),
+        ResponseCache::key("http://localhost:8080/v3", "/categories", &[])
+    );
+    assert_eq!(
+        ResponseCache::key(BASE_URL, "/categories", &[]),
+        ResponseCache::key(&format!("{BASE_URL}/"), "/categories", &[])
+    );
+}
+
+#[test]
+fn test_key_of_equal_payloads_is_equal() {
+    let key = || {
+        let payload: BusinessSearchPayload = BusinessSearchPayload::builder()
+            .location("NYC".to_string())
+            .price(HashSet::from([
+                PriceType::OneDollar,
+                PriceType::TwoDollar,
+                PriceType::ThreeDollar,
+                PriceType::FourDollar,
+            ]))
+            .attributes(HashSet::from([
+                Attribute::HotAndNew,
+                Attribute::Deals,
+                Attribute::Reservation,
+                Attribute::OpenToAll,
+            ]))
+            .build()
+            .unwrap();
+        ResponseCache::key(BASE_URL, "/businesses/search", &payload.to_query_params())
+    };
+    let first: String = key();
+    for _ in 0..20 {
+        assert_eq!(first, key());
+    }
+}
+
+#[test]
+fn test_endpoint_from_path() {
+    assert_eq!(
+        Some(Endpoint::BusinessSearch),
+        Endpoint::from_path("/businesses/search")
+    );
+    assert_eq!(
+        Some(Endpoint::BusinessDetails),
+        Endpoint::from_path("/businesses/some-alias")
+    );
+    assert_eq!(
+        Some(Endpoint::BusinessReviews),
+        Endpoint::from_path("/businesses/some-alias/reviews")
+    );
+    assert_eq!(
+        Some(Endpoint::TransactionSearch),
+        Endpoint::from_path("/transactions/delivery/search")
+    );
+    assert_eq!(None, Endpoint::from_path("/graphql"));
+}
+
+#[test]
+fn test_memory_cache_evicts_least_recently_used() {
+    let cache: MemoryCache = MemoryCache::new(2);
+    cache.insert("a", Bytes::from("a"), Duration::from_secs(60));
+    cache.insert("b", Bytes::from("b"), Duration::from_secs(60));
+    assert_eq!(Some(Bytes::from("a")), cache.get("a"));
+
+    cache.insert("c", Bytes::from("c"), Duration::from_secs(60));
+    assert_eq!(2, cache.len());
+    assert_eq!(Some(Bytes::from("a")), cache.get("a"));
+    assert_eq!(None, cache.get("b"));
+    assert_eq!(Some(Bytes::from("c")), cache.get("c"));
+}
+
+#[test]
+fn test_memory_cache_expires() {
+    let cache: MemoryCache = MemoryCache::new(2);
+    cache.insert("a", Bytes::from("a"), Duration::ZERO);
+    assert_eq!(None, cache.get("a"));
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_disk_cache() {
+    let directory = env::temp_dir().join(format!("yelp-fusion-rs-cache-{}", std::process::id()));
+    let cache: DiskCache = DiskCache::new(&directory).unwrap();
+    cache.insert("a", Bytes::from(r#"{"a":1}"#), Duration::from_secs(60));
+    cache.insert("b", Bytes::from(r#"{"b":2}"#), Duration::ZERO);
+    cache.insert("c", Bytes::from(r#"{"c":3}"#), Duration::from_millis(500));
+
+    // a fresh instance reads what the first one wrote
+    let cache: DiskCache = DiskCache::new(&directory).unwrap();
+    assert_eq!(Some(Bytes::from(r#"{"a":1}"#)), cache.get("a"));
+    assert_eq!(None, cache.get("b"));
+    assert_eq!(Some(Bytes::from(r#"{"c":3}"#)), cache.get("c"));
+    assert_eq!(None, cache.get("d"));
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn test_disk_cache_concurrent_inserts() {
+    let directory = env::temp_dir().join(format!(
+        "yelp-fusion-rs-cache-concurrent-{}",
+        std::process::id()
+    ));
+    let cache: DiskCache = DiskCache::new(&directory).unwrap();
+    thread::scope(|scope| {
+        for i in 0..8 {
+            let cache: &DiskCache = &cache;
+            scope.spawn(move || {
+                for _ in 0..20 {
+                    cache.insert(
+                        "a",
+                        Bytes::from(format!(r#"{{"a":{i}}}"#)),
+                        Duration::from_secs(60),
+                    );
+                }
+            });
+        }
+    });
+
+    // every write went through its own temporary file, so the entry is whole and none are left
+    assert!(cache.get("a").is_some());
+    assert_eq!(1, std::fs::read_dir(&directory).unwrap().count());
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[cfg(feature = "reqwest")]
+#[test]
+fn test_client_serves_cache_hits() {
+    let requests: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+    let server_requests: Arc<AtomicUsize> = Arc::clone(&requests);
+    let base_url: String = spawn_server(move |_| -> MockResponse {
+        server_requests.fetch_add(1, Ordering::SeqCst);
+        (
+            "200 OK",
+            String::new(),
+            r#"{"total":0,"businesses":[],"categories":[]}"#.to_string(),
+        )
+    });
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(base_url)
+        .cache(ResponseCache::in_memory(16).with_ttl(Endpoint::AllCategories, Duration::ZERO))
+        .build()
+        .unwrap();
+    let payload: BusinessSearchPayload = BusinessSearchPayload::builder()
+        .location("New York City".to_string())
+        .build()
+        .unwrap();
+
+    let (_, meta): (_, ResponseMeta) = client.business_search_with_meta(&payload).unwrap();
+    assert!(!meta.cache_hit);
+    let (_, meta): (_, ResponseMeta) = client.business_search_with_meta(&payload).unwrap();
+    assert!(meta.cache_hit);
+    assert_eq!(1, requests.load(Ordering::SeqCst));
+
+    // caching is disabled for All Categories
+    client.all_categories(None).unwrap();
+    client.all_categories(None).unwrap();
+    assert_eq!(3, requests.load(Ordering::SeqCst));
+}
+
+#[cfg(feature = "reqwest")]
+#[test]
+fn test_shared_cache_keeps_servers_apart() {
+    let spawn_counting_server = |requests: &Arc<AtomicUsize>| {
+        let server_requests: Arc<AtomicUsize> = Arc::clone(requests);
+        spawn_server(move |_| -> MockResponse {
+            server_requests.fetch_add(1, Ordering::SeqCst);
+            ("200 OK", String::new(), r#"{"categories":[]}"#.to_string())
+        })
+    };
+    let cache: ResponseCache = ResponseCache::in_memory(16);
+    let requests: [Arc<AtomicUsize>; 2] = [Arc::default(), Arc::default()];
+    for server_requests in &requests {
+        let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+            .base_url(spawn_counting_server(server_requests))
+            .cache(cache.clone())
+            .build()
+            .unwrap();
+        client.all_categories(None).unwrap();
+        client.all_categories(None).unwrap();
+    }
+
+    assert_eq!(1, requests[0].load(Ordering::SeqCst));
+    assert_eq!(1, requests[1].load(Ordering::SeqCst));
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "reqwest")]
+
+pub mod common;
+
+use common::{MockResponse, spawn_server};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use yelp_fusion_rs::endpoints::AllCategoriesResponse;
+use yelp_fusion_rs::error::{ApiErrorCode, Error};
+use yelp_fusion_rs::graphql::GraphQlRequest;
+use yelp_fusion_rs::response_meta::{RateLimit, ResponseMeta};
+use yelp_fusion_rs::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+
+const RATE_LIMIT_HEADERS: &str = "RateLimit-DailyLimit: 5000\r\nRateLimit-Remaining: 0\r\nRateLimit-ResetTime: 2026-10-19T00:00:00+00:00\r\nX-Request-Id: abc\r\n";
+
+fn rate_limit() -> RateLimit {
+    RateLimit {
+        daily_limit: Some(5000),
+        remaining: Some(0),
+        reset_time: Some(String::from("2026-10-19T00:00:00+00:00")),
+    }
+}
+
+fn respond(request: &str) -> MockResponse {
+    if request.starts_with("get /v3/categories ") {
+        (
+            "200 OK",
+            RATE_LIMIT_HEADERS.to_string(),
+            String::from(r#"{"categories":[]}"#),
+        )
+    } else if request.starts_with("post /v3/graphql ") {
+        (
+            "200 OK",
+            RATE_LIMIT_HEADERS.to_string(),
+            String::from(r#"{"data":null,"errors":[{"message":"Business not found"}]}"#),
+        )
+    } else {
+        (
+            "429 Too Many Requests",
+            RATE_LIMIT_HEADERS.to_string(),
+            String::from(
+                r#"{"error":{"code":"ACCESS_LIMIT_REACHED","description":"You've reached the access limit for this client."}}"#,
+            ),
+        )
+    }
+}
+
+#[tokio::test]
+async fn test_with_meta() {
+    let client: YelpFusionClient = YelpFusionClient::builder("api_key")
+        .base_url(format!("{}/v3", spawn_server(respond)))
+        .build()
+        .unwrap();
+
+    let (response, meta): (AllCategoriesResponse, ResponseMeta) =
+        client.all_categories_with_meta(None).await.unwrap();
+    assert!(response.categories.is_empty());
+    assert_eq!(Some(rate_limit()), meta.rate_limit);
+    assert_eq!(Some("abc"), meta.request_id.as_deref());
+}
+
+#[test]
+fn test_failed_response_meta() {
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(format!("{}/v3", spawn_server(respond)))
+        .build()
+        .unwrap();
+
+    let error: Error = client.event_lookup("some-event", None).unwrap_err();
+    assert_eq!(
+        Some(&ApiErrorCode::AccessLimitReached),
+        error.api_error_code()
+    );
+    assert_eq!(
+        Some(rate_limit()),
+        error
+            .response_meta()
+            .and_then(|meta| meta.rate_limit.clone())
+    );
+}
+
+#[test]
+fn test_graphql_error_meta() {
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(format!("{}/v3", spawn_server(respond)))
+        .build()
+        .unwrap();
+
+    let error: Error = client
+        .graphql_with_meta::<serde_json::Value>(&GraphQlRequest::new(
+            String::from("{ business(id: \"a\") { id } }"),
+            None,
+        ))
+        .unwrap_err();
+    assert_eq!(
+        Some("abc"),
+        error
+            .response_meta()
+            .and_then(|meta| meta.request_id.as_deref())
+    );
+}
+
+#[test]
+fn test_rate_limit_reset_at() {
+    let with_reset_time = |reset_time: &str| RateLimit {
+        daily_limit: None,
+        remaining: None,
+        reset_time: Some(reset_time.to_string()),
+    };
+    let expected: SystemTime = UNIX_EPOCH + Duration::from_secs(1_709_251_200);
+
+    assert_eq!(
+        Some(expected),
+        with_reset_time("2024-03-01T00:00:00+00:00").reset_at()
+    );
+    assert_eq!(
+        Some(expected),
+        with_reset_time("2024-03-01T00:00:00Z").reset_at()
+    );
+    assert_eq!(
+        Some(expected),
+        with_reset_time("2024-02-29T16:00:00.123-08:00").reset_at()
+    );
+    assert_eq!(None, with_reset_time("tomorrow").reset_at());
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+use yelp_fusion_rs::retry::RetryPolicy;
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 5,
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_millis(500),
+        jitter: false,
+        respect_retry_after: true,
+    }
+}
+
+#[test]
+fn test_none_never_retries() {
+    assert_eq!(None, RetryPolicy::none().delay(1, None));
+}
+
+#[test]
+fn test_exponential_backoff() {
+    let retry_policy: RetryPolicy = retry_policy();
+    assert_eq!(
+        Some(Duration::from_millis(100)),
+        retry_policy.delay(1, None)
+    );
+    assert_eq!(
+        Some(Duration::from_millis(200)),
+        retry_policy.delay(2, None)
+    );
+    assert_eq!(
+        Some(Duration::from_millis(400)),
+        retry_policy.delay(3, None)
+    );
+    assert_eq!(
+        Some(Duration::from_millis(500)),
+        retry_policy.delay(4, None)
+    );
+    assert_eq!(None, retry_policy.delay(5, None));
+}
+
+#[test]
+fn test_jitter_stays_within_backoff() {
+    let retry_policy: RetryPolicy = RetryPolicy {
+        jitter: true,
+        ..retry_policy()
+    };
+    for _ in 0..100 {
+        let delay: Duration = retry_policy.delay(3, None).unwrap();
+        assert!(delay <= Duration::from_millis(400));
+    }
+}
+
+#[test]
+fn test_retry_after() {
+    let retry_policy: RetryPolicy = retry_policy();
+    assert_eq!(
+        Some(Duration::from_millis(300)),
+        retry_policy.delay(1, Some(Duration::from_millis(300)))
+    );
+    assert_eq!(None, retry_policy.delay(1, Some(Duration::from_secs(60))));
+
+    let ignore_retry_after: RetryPolicy = RetryPolicy {
+        respect_retry_after: false,
+        ..retry_policy
+    };
+    assert_eq!(
+        Some(Duration::from_millis(100)),
+        ignore_retry_after.delay(1, Some(Duration::from_secs(60)))
+    );
+}
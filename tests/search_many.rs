@@ -0,0 +1,120 @@
+#![cfg(feature = "reqwest")]
+
+pub mod common;
+
+use common::{MockResponse, spawn_server};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use yelp_fusion_rs::endpoints::{BusinessSearchPayload, BusinessSearchResponse};
+use yelp_fusion_rs::error::{ApiErrorCode, Error};
+use yelp_fusion_rs::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+
+/// Reports the search's term as its `total`, failing terms that aren't numbers.
+fn respond(request: &str) -> MockResponse {
+    let start: usize = request.find("term=").unwrap() + "term=".len();
+    let term: &str = request[start..].split(['&', ' ']).next().unwrap();
+    match term.parse::<usize>() {
+        Ok(total) => (
+            "200 OK",
+            String::new(),
+            format!(r#"{{"total":{total},"businesses":[]}}"#),
+        ),
+        Err(_) => (
+            "400 Bad Request",
+            String::new(),
+            r#"{"error":{"code":"VALIDATION_ERROR","description":"invalid term"}}"#.to_string(),
+        ),
+    }
+}
+
+/// Mock server recording the peak number of requests it was serving at once, returning its base
+/// URL along with that peak.
+fn spawn_peak_recording_server() -> (String, Arc<AtomicUsize>) {
+    let in_flight: Arc<AtomicUsize> = Arc::default();
+    let peak: Arc<AtomicUsize> = Arc::default();
+    let recorded_peak: Arc<AtomicUsize> = Arc::clone(&peak);
+    let base_url: String = spawn_server(move |request| {
+        let current: usize = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        recorded_peak.fetch_max(current, Ordering::SeqCst);
+        // keep the request in flight long enough for the others to overlap it
+        thread::sleep(Duration::from_millis(50));
+        in_flight.fetch_sub(1, Ordering::SeqCst);
+        respond(request)
+    });
+    (base_url, peak)
+}
+
+fn payloads() -> Vec<BusinessSearchPayload> {
+    ["1", "2", "fail", "4", "5", "6", "7"]
+        .iter()
+        .map(|term| {
+            BusinessSearchPayload::builder()
+                .term((*term).to_string())
+                .location("New York City".to_string())
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+fn assert_results(results: &[Result<BusinessSearchResponse, Error>]) {
+    assert_eq!(7, results.len());
+    for (index, result) in results.iter().enumerate() {
+        match result {
+            Ok(response) => assert_eq!(index + 1, response.total),
+            Err(e) => {
+                assert_eq!(2, index);
+                assert_eq!(Some(&ApiErrorCode::ValidationError), e.api_error_code());
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_search_many_keeps_input_order() {
+    let client: YelpFusionClient = YelpFusionClient::builder("api_key")
+        .base_url(spawn_server(respond))
+        .build()
+        .unwrap();
+    assert_results(&client.search_many(&payloads(), 3).await);
+}
+
+#[test]
+fn test_blocking_search_many_keeps_input_order() {
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(spawn_server(respond))
+        .build()
+        .unwrap();
+    assert_results(&client.search_many(&payloads(), 3));
+    assert!(client.search_many(&[], 3).is_empty());
+}
+
+#[tokio::test]
+async fn test_search_many_limits_concurrency() {
+    let (base_url, peak): (String, Arc<AtomicUsize>) = spawn_peak_recording_server();
+    let client: YelpFusionClient = YelpFusionClient::builder("api_key")
+        .base_url(base_url)
+        .build()
+        .unwrap();
+    assert_results(&client.search_many(&payloads(), 3).await);
+
+    let peak: usize = peak.load(Ordering::SeqCst);
+    assert!(peak > 1, "requests were not sent concurrently");
+    assert!(peak <= 3, "{peak} requests were sent concurrently");
+}
+
+#[test]
+fn test_blocking_search_many_limits_concurrency() {
+    let (base_url, peak): (String, Arc<AtomicUsize>) = spawn_peak_recording_server();
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(base_url)
+        .build()
+        .unwrap();
+    assert_results(&client.search_many(&payloads(), 3));
+
+    let peak: usize = peak.load(Ordering::SeqCst);
+    assert!(peak > 1, "requests were not sent concurrently");
+    assert!(peak <= 3, "{peak} requests were sent concurrently");
+}
@@ -0,0 +1,96 @@
+use yelp_fusion_rs::endpoints::{TransactionSearchPayload, TransactionSearchPayloadError};
+use yelp_fusion_rs::models::{Coordinates, TransactionType};
+
+#[test]
+fn test_constructor_location() {
+    let transaction_search_payload_result: Result<
+        TransactionSearchPayload,
+        TransactionSearchPayloadError,
+    > = TransactionSearchPayload::new(
+        TransactionType::Delivery,
+        Some(String::from("Chicago, IL")),
+        None,
+    );
+    assert!(transaction_search_payload_result.is_ok());
+}
+
+#[test]
+fn test_constructor_coordinates() {
+    let transaction_search_payload_result: Result<
+        TransactionSearchPayload,
+        TransactionSearchPayloadError,
+    > = TransactionSearchPayload::new(
+        TransactionType::Delivery,
+        None,
+        Some(Coordinates::new(37.772_484, -122.396_68)),
+    );
+    assert!(transaction_search_payload_result.is_ok());
+}
+
+#[test]
+fn test_constructor_unsupported_transaction_type_error() {
+    let transaction_search_payload_result: Result<
+        TransactionSearchPayload,
+        TransactionSearchPayloadError,
+    > = TransactionSearchPayload::new(
+        TransactionType::Pickup,
+        Some(String::from("Chicago, IL")),
+        None,
+    );
+    match transaction_search_payload_result {
+        Ok(_) => panic!("Returned a TransactionSearchPayload!"),
+        Err(e) => match e {
+            TransactionSearchPayloadError::UnsupportedTransactionType(_) => (),
+            TransactionSearchPayloadError::BothLocationAndLatLongSet => {
+                panic!("Returned BothLocationAndLatLongSet!")
+            }
+            TransactionSearchPayloadError::NeitherLocationNorLatLongSet => {
+                panic!("Returned NeitherLocationNorLatLongSet!")
+            }
+        },
+    }
+}
+
+#[test]
+fn test_constructor_location_and_coordinates_set_error() {
+    let transaction_search_payload_result: Result<
+        TransactionSearchPayload,
+        TransactionSearchPayloadError,
+    > = TransactionSearchPayload::new(
+        TransactionType::Delivery,
+        Some(String::from("Chicago, IL")),
+        Some(Coordinates::new(37.772_484, -122.396_68)),
+    );
+    match transaction_search_payload_result {
+        Ok(_) => panic!("Returned a TransactionSearchPayload!"),
+        Err(e) => match e {
+            TransactionSearchPayloadError::UnsupportedTransactionType(_) => {
+                panic!("Returned UnsupportedTransactionType!")
+            }
+            TransactionSearchPayloadError::BothLocationAndLatLongSet => (),
+            TransactionSearchPayloadError::NeitherLocationNorLatLongSet => {
+                panic!("Returned NeitherLocationNorLatLongSet!")
+            }
+        },
+    }
+}
+
+#[test]
+fn test_constructor_neither_location_nor_coordinates_set_error() {
+    let transaction_search_payload_result: Result<
+        TransactionSearchPayload,
+        TransactionSearchPayloadError,
+    > = TransactionSearchPayload::new(TransactionType::Delivery, None, None);
+    match transaction_search_payload_result {
+        Ok(_) => panic!("Returned a TransactionSearchPayload!"),
+        Err(e) => match e {
+            TransactionSearchPayloadError::UnsupportedTransactionType(_) => {
+                panic!("Returned UnsupportedTransactionType!")
+            }
+            TransactionSearchPayloadError::BothLocationAndLatLongSet => {
+                panic!("Returned BothLocationAndLatLongSet!")
+            }
+            TransactionSearchPayloadError::NeitherLocationNorLatLongSet => (),
+        },
+    }
+}
@@ -0,0 +1,221 @@
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http::header::{AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT};
+use http::{Method, StatusCode};
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use yelp_fusion_rs::api_key::ApiKeyError;
+use yelp_fusion_rs::endpoints::AllCategoriesResponse;
+use yelp_fusion_rs::error::Error;
+use yelp_fusion_rs::retry::RetryPolicy;
+use yelp_fusion_rs::transport::{
+    BlockingTransport, Transport, TransportRequest, TransportResponse,
+};
+use yelp_fusion_rs::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+
+/// Transport recording every request and returning canned results, in order.
+#[derive(Debug, Clone, Default)]
+struct FakeTransport {
+    requests: Arc<Mutex<Vec<TransportRequest>>>,
+    results: Arc<Mutex<VecDeque<Result<TransportResponse, Error>>>>,
+}
+
+impl FakeTransport {
+    fn new(results: Vec<Result<TransportResponse, Error>>) -> Self {
+        Self {
+            requests: Arc::default(),
+            results: Arc::new(Mutex::new(results.into())),
+        }
+    }
+
+    fn requests(&self) -> Vec<TransportRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        self.requests.lock().unwrap().push(request);
+        self.results.lock().unwrap().pop_front().unwrap()
+    }
+}
+
+impl Transport for FakeTransport {
+    fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse, Error>> {
+        Box::pin(async move { self.respond(request) })
+    }
+}
+
+impl BlockingTransport for FakeTransport {
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, Error> {
+        self.respond(request)
+    }
+}
+
+fn categories_response() -> TransportResponse {
+    TransportResponse {
+        status_code: StatusCode::OK,
+        headers: HeaderMap::new(),
+        body: Bytes::from(r#"{"categories":[]}"#),
+    }
+}
+
+fn connection_error(retryable: bool) -> Result<TransportResponse, Error> {
+    Err(Error::TransportError {
+        error: Box::new(io::Error::from(io::ErrorKind::ConnectionReset)),
+        retryable,
+    })
+}
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(1),
+        jitter: false,
+        respect_retry_after: true,
+    }
+}
+
+#[tokio::test]
+async fn test_sends_authorized_requests_through_transport() {
+    let transport: FakeTransport = FakeTransport::new(vec![Ok(categories_response())]);
+    let client: YelpFusionClient = YelpFusionClient::builder("api_key")
+        .base_url("https://example.com/v3/".to_string())
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+
+    let response: AllCategoriesResponse = client
+        .all_categories(Some("fr_FR".to_string()))
+        .await
+        .unwrap();
+    assert!(response.categories.is_empty());
+
+    let requests: Vec<TransportRequest> = transport.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!(Method::GET, requests[0].method);
+    assert_eq!(
+        "https://example.com/v3/categories?locale=fr_FR",
+        requests[0].url.as_str()
+    );
+    assert_eq!("Bearer api_key", requests[0].headers[AUTHORIZATION]);
+    assert!(requests[0].headers[AUTHORIZATION].is_sensitive());
+    assert_eq!(None, requests[0].body);
+}
+
+#[cfg(feature = "reqwest")]
+#[test]
+fn test_blocking_sends_requests_through_transport() {
+    let transport: FakeTransport = FakeTransport::new(vec![Ok(categories_response())]);
+    let client: BlockingYelpFusionClient =
+        BlockingYelpFusionClient::new("api_key", None).with_transport(transport.clone());
+
+    client.all_categories(None).unwrap();
+
+    let requests: Vec<TransportRequest> = transport.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!(
+        "https://api.yelp.com/v3/categories",
+        requests[0].url.as_str()
+    );
+    assert_eq!("Bearer api_key", requests[0].headers[AUTHORIZATION]);
+}
+
+#[test]
+fn test_builder_headers_are_sent_through_transport() {
+    let mut default_headers: HeaderMap = HeaderMap::new();
+    default_headers.insert("x-custom", HeaderValue::from_static("value"));
+    let transport: FakeTransport = FakeTransport::new(vec![Ok(categories_response())]);
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .user_agent("yelp-fusion-rs-test".to_string())
+        .default_headers(default_headers)
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+
+    client.all_categories(None).unwrap();
+
+    let requests: Vec<TransportRequest> = transport.requests();
+    assert_eq!("yelp-fusion-rs-test", requests[0].headers[USER_AGENT]);
+    assert_eq!("value", requests[0].headers["x-custom"]);
+    assert_eq!("Bearer api_key", requests[0].headers[AUTHORIZATION]);
+}
+
+#[test]
+fn test_builder_timeouts_with_transport_error() {
+    let client: Result<YelpFusionClient, Error> = YelpFusionClient::builder("api_key")
+        .timeout(Duration::from_secs(10))
+        .transport(FakeTransport::default())
+        .build();
+    assert!(matches!(
+        client,
+        Err(Error::TransportError {
+            retryable: false,
+            ..
+        })
+    ));
+
+    let client: Result<BlockingYelpFusionClient, Error> =
+        BlockingYelpFusionClient::builder("api_key")
+            .connect_timeout(Duration::from_secs(2))
+            .transport(FakeTransport::default())
+            .build();
+    assert!(matches!(
+        client,
+        Err(Error::TransportError {
+            retryable: false,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_retries_retryable_transport_errors() {
+    let transport: FakeTransport = FakeTransport::new(vec![
+        connection_error(true),
+        connection_error(true),
+        Ok(categories_response()),
+    ]);
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .transport(transport.clone())
+        .retry_policy(retry_policy())
+        .build()
+        .unwrap();
+
+    client.all_categories(None).unwrap();
+    assert_eq!(3, transport.requests().len());
+}
+
+#[tokio::test]
+async fn test_does_not_retry_other_transport_errors() {
+    let transport: FakeTransport =
+        FakeTransport::new(vec![connection_error(false), Ok(categories_response())]);
+    let client: YelpFusionClient = YelpFusionClient::builder("api_key")
+        .transport(transport.clone())
+        .retry_policy(retry_policy())
+        .build()
+        .unwrap();
+
+    match client.all_categories(None).await {
+        Err(Error::TransportError { retryable, .. }) => assert!(!retryable),
+        _ => panic!("Returned a response!"),
+    }
+    assert_eq!(1, transport.requests().len());
+}
+
+#[test]
+fn test_invalid_api_key_is_not_sent() {
+    let transport: FakeTransport = FakeTransport::new(vec![Ok(categories_response())]);
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api\nkey")
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+
+    match client.all_categories(None) {
+        Err(Error::InvalidApiKey(ApiKeyError::InvalidHeaderValue)) => {}
+        Err(e) => panic!("Returned {e}!"),
+        Ok(_) => panic!("Returned a response!"),
+    }
+    assert!(transport.requests().is_empty());
+}
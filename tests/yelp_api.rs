@@ -0,0 +1,228 @@
+pub mod common;
+
+use common::business_json;
+#[cfg(feature = "reqwest")]
+use common::{MockResponse, spawn_server};
+use std::time::Duration;
+use yelp_fusion_rs::endpoints::{
+    AllCategoriesResponse, BusinessSearchPayload, BusinessSearchResponse, Endpoint,
+};
+use yelp_fusion_rs::error::Error;
+use yelp_fusion_rs::graphql::{GraphQlBusiness, GraphQlRequest};
+use yelp_fusion_rs::models::Coordinates;
+use yelp_fusion_rs::response_meta::{RateLimit, ResponseMeta};
+use yelp_fusion_rs::yelp_api::{BlockingYelpApi, MockYelpApi, YelpApi, YelpApiCall};
+#[cfg(feature = "reqwest")]
+use yelp_fusion_rs::yelp_fusion::{BlockingYelpFusionClient, YelpFusionClient};
+
+fn search_response_json(ids: &[&str]) -> String {
+    let businesses: Vec<String> = ids
+        .iter()
+        .map(|id| business_json(id, Coordinates::new(0.0, 0.0)))
+        .collect();
+    format!(
+        r#"{{"total":{},"businesses":[{}]}}"#,
+        ids.len(),
+        businesses.join(",")
+    )
+}
+
+fn search_response(ids: &[&str]) -> BusinessSearchResponse {
+    serde_json::from_str(&search_response_json(ids)).unwrap()
+}
+
+#[cfg(feature = "reqwest")]
+fn respond(_request: &str) -> MockResponse {
+    ("200 OK", String::new(), search_response_json(&["a", "b"]))
+}
+
+fn payload(location: &str) -> BusinessSearchPayload {
+    BusinessSearchPayload::builder()
+        .location(location.to_string())
+        .build()
+        .unwrap()
+}
+
+/// Business logic under test: the IDs of the businesses found in `location`.
+async fn business_ids(api: &dyn YelpApi, location: &str) -> Result<Vec<String>, Error> {
+    let response: BusinessSearchResponse = api.business_search(&payload(location)).await?;
+    Ok(response
+        .businesses
+        .into_iter()
+        .map(|business| business.id)
+        .collect())
+}
+
+fn blocking_business_ids(api: &dyn BlockingYelpApi, location: &str) -> Result<Vec<String>, Error> {
+    let response: BusinessSearchResponse = api.business_search(&payload(location))?;
+    Ok(response
+        .businesses
+        .into_iter()
+        .map(|business| business.id)
+        .collect())
+}
+
+#[cfg(feature = "reqwest")]
+#[tokio::test]
+async fn test_client_implements_yelp_api() {
+    let client: YelpFusionClient = YelpFusionClient::builder("api_key")
+        .base_url(spawn_server(respond))
+        .build()
+        .unwrap();
+    assert_eq!(vec!["a", "b"], business_ids(&client, "NYC").await.unwrap());
+}
+
+#[cfg(feature = "reqwest")]
+#[test]
+fn test_blocking_client_implements_blocking_yelp_api() {
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(spawn_server(respond))
+        .build()
+        .unwrap();
+    assert_eq!(
+        vec!["a", "b"],
+        blocking_business_ids(&client, "NYC").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_mock_returns_canned_responses_in_order() {
+    let mock: MockYelpApi = MockYelpApi::new();
+    mock.push_business_search(Ok(search_response(&["a"])));
+    mock.push_business_search(Ok(search_response(&["b", "c"])));
+    mock.push_business_search(Err(Error::AllApiKeysBenched {
+        available_in: Duration::from_secs(60),
+    }));
+
+    assert_eq!(vec!["a"], business_ids(&mock, "NYC").await.unwrap());
+    assert_eq!(vec!["b", "c"], business_ids(&mock, "SF").await.unwrap());
+    match business_ids(&mock, "LA").await {
+        Err(Error::AllApiKeysBenched { .. }) => {}
+        _ => panic!("Returned a response!"),
+    }
+}
+
+#[test]
+fn test_mock_records_calls() {
+    let mock: MockYelpApi = MockYelpApi::new();
+    mock.push_business_search(Ok(search_response(&[])));
+    mock.push_all_categories(Ok(AllCategoriesResponse { categories: vec![] }));
+
+    blocking_business_ids(&mock.clone(), "NYC").unwrap();
+    BlockingYelpApi::all_categories(&mock, Some("fr_FR".to_string())).unwrap();
+
+    let calls: Vec<YelpApiCall> = mock.calls();
+    assert_eq!(2, calls.len());
+    match &calls[0] {
+        YelpApiCall::BusinessSearch(payload) => {
+            assert_eq!(Some("NYC".to_string()), payload.location);
+        }
+        _ => panic!("Recorded another endpoint!"),
+    }
+    match &calls[1] {
+        YelpApiCall::AllCategories { locale } => assert_eq!(Some("fr_FR"), locale.as_deref()),
+        _ => panic!("Recorded another endpoint!"),
+    }
+    assert_eq!(1, mock.calls_to(Endpoint::AllCategories).len());
+    assert!(mock.calls_to(Endpoint::EventSearch).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "no response left for business_search")]
+fn test_mock_panics_without_response() {
+    let _ = blocking_business_ids(&MockYelpApi::new(), "NYC");
+}
+
+#[tokio::test]
+async fn test_mock_returns_response_meta() {
+    let mock: MockYelpApi = MockYelpApi::new();
+    mock.push_business_search(Ok(search_response(&["a"])));
+    mock.push_business_search(Ok(search_response(&["b"])));
+
+    let api: &dyn YelpApi = &mock;
+    let (response, meta): (BusinessSearchResponse, ResponseMeta) = api
+        .business_search_with_meta(&payload("NYC"))
+        .await
+        .unwrap();
+    assert_eq!("a", response.businesses[0].id);
+    assert_eq!(200, meta.status_code.as_u16());
+    assert_eq!(None, meta.rate_limit);
+
+    mock.set_response_meta(ResponseMeta {
+        status_code: meta.status_code,
+        request_id: Some("abc".to_string()),
+        rate_limit: Some(RateLimit {
+            daily_limit: Some(5000),
+            remaining: Some(4999),
+            reset_time: None,
+        }),
+        latency: Duration::ZERO,
+        cache_hit: false,
+    });
+    let (_, meta): (BusinessSearchResponse, ResponseMeta) =
+        api.business_search_with_meta(&payload("SF")).await.unwrap();
+    assert_eq!(Some("abc"), meta.request_id.as_deref());
+    assert_eq!(Some(4999), meta.rate_limit.unwrap().remaining);
+    assert_eq!(2, mock.calls_to(Endpoint::BusinessSearch).len());
+}
+
+#[test]
+fn test_mock_graphql() {
+    let mock: MockYelpApi = MockYelpApi::new();
+    mock.push_graphql(Ok(
+        serde_json::json!({"business": {"id": "a", "photos": []}}),
+    ));
+    mock.push_graphql(Ok(serde_json::json!({"business": null})));
+    mock.push_graphql_business(Ok(serde_json::from_str::<GraphQlBusiness>(
+        r#"{"id": "b", "photos": []}"#,
+    )
+    .unwrap()));
+
+    let request: GraphQlRequest =
+        GraphQlRequest::new(String::from("{ business(id: \"a\") { id } }"), None);
+    let data: serde_json::Value = BlockingYelpApi::graphql(&mock, &request).unwrap();
+    assert_eq!("a", data["business"]["id"]);
+    // data that doesn't deserialize into the requested type is an error, not a panic
+    match BlockingYelpApi::graphql::<BusinessSearchResponse>(&mock, &request) {
+        Err(Error::SerdeJsonError(_)) => {}
+        _ => panic!("Returned a response!"),
+    }
+    let business: GraphQlBusiness = BlockingYelpApi::graphql_business(&mock, "b", Some(3)).unwrap();
+    assert_eq!("b", business.id);
+
+    let calls: Vec<YelpApiCall> = mock.calls();
+    assert_eq!(3, calls.len());
+    assert!(calls.iter().all(|call| call.endpoint().is_none()));
+    match &calls[2] {
+        YelpApiCall::GraphQlBusiness { id, review_limit } => {
+            assert_eq!("b", id);
+            assert_eq!(Some(3), *review_limit);
+        }
+        _ => panic!("Recorded another method!"),
+    }
+}
+
+#[test]
+#[should_panic(expected = "no response left for graphql_reviews")]
+fn test_mock_panics_without_graphql_response() {
+    let _ = BlockingYelpApi::graphql_reviews(&MockYelpApi::new(), "a", None, None);
+}
+
+#[cfg(feature = "reqwest")]
+#[tokio::test]
+async fn test_client_implements_yelp_api_graphql() {
+    let client: YelpFusionClient = YelpFusionClient::builder("api_key")
+        .base_url(spawn_server(|_| {
+            (
+                "200 OK",
+                String::new(),
+                r#"{"data":{"business":{"id":"a","photos":[]}}}"#.to_string(),
+            )
+        }))
+        .build()
+        .unwrap();
+
+    let api: &dyn YelpApi = &client;
+    let business: GraphQlBusiness = api.graphql_business("a", None).await.unwrap();
+    assert_eq!("a", business.id);
+}
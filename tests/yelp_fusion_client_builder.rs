@@ -0,0 +1,125 @@
+#![cfg(feature = "reqwest")]
+
+pub mod common;
+
+use common::spawn_server;
+use http::header::{HeaderMap, HeaderValue};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use yelp_fusion_rs::endpoints::BusinessMatchPayload;
+use yelp_fusion_rs::error::Error;
+use yelp_fusion_rs::yelp_fusion::{
+    BlockingYelpFusionClient, BlockingYelpFusionClientBuilder, YelpFusionClient,
+    YelpFusionClientBuilder,
+};
+
+#[test]
+fn test_builder_defaults() {
+    let builder: YelpFusionClientBuilder = YelpFusionClient::builder("api_key".to_string());
+    assert_eq!("https://api.yelp.com/v3", builder.base_url);
+    assert_eq!(None, builder.timeout);
+    assert_eq!(None, builder.connect_timeout);
+    assert_eq!(None, builder.user_agent);
+    assert_eq!(None, builder.default_locale);
+    assert!(builder.default_headers.is_empty());
+}
+
+#[test]
+fn test_builder_build() {
+    let mut default_headers: HeaderMap = HeaderMap::new();
+    default_headers.insert("x-custom", HeaderValue::from_static("value"));
+
+    let client: Result<YelpFusionClient, Error> = YelpFusionClient::builder("api_key".to_string())
+        .base_url("http://localhost:8080/v3/".to_string())
+        .timeout(Duration::from_secs(10))
+        .connect_timeout(Duration::from_secs(2))
+        .user_agent("yelp-fusion-rs-test".to_string())
+        .default_locale("en_US".to_string())
+        .default_headers(default_headers)
+        .build();
+    assert!(client.is_ok());
+}
+
+#[test]
+fn test_blocking_builder_uses_base_url() {
+    let builder: BlockingYelpFusionClientBuilder =
+        BlockingYelpFusionClient::builder("api_key".to_string());
+    let client: BlockingYelpFusionClient = builder
+        .clone()
+        .base_url("http://127.0.0.1:1".to_string())
+        .connect_timeout(Duration::from_millis(500))
+        .build()
+        .unwrap();
+
+    match client.business_details("business_id", None) {
+        Ok(_) => panic!("Returned a response!"),
+        Err(Error::TransportError { error, retryable }) => {
+            assert!(retryable);
+            assert!(error.downcast_ref::<reqwest::Error>().unwrap().is_connect());
+        }
+        Err(e) => panic!("Returned {e}!"),
+    }
+}
+
+#[test]
+fn test_default_locale_only_sent_to_endpoints_accepting_it() {
+    let request_lines: Arc<Mutex<Vec<String>>> = Arc::default();
+    let recorded_request_lines: Arc<Mutex<Vec<String>>> = Arc::clone(&request_lines);
+    let base_url: String = spawn_server(move |request| {
+        let request_line: &str = request.lines().next().unwrap();
+        recorded_request_lines
+            .lock()
+            .unwrap()
+            .push(request_line.to_string());
+        let body: &str = if request_line.contains("/categories") {
+            r#"{"categories":[]}"#
+        } else {
+            r#"{"businesses":[]}"#
+        };
+        ("200 OK", String::new(), body.to_string())
+    });
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(base_url)
+        .default_locale("fr_FR".to_string())
+        .build()
+        .unwrap();
+
+    client.all_categories(None).unwrap();
+    let payload: BusinessMatchPayload = BusinessMatchPayload::builder()
+        .name(String::from("Gary Danko"))
+        .address1(String::from("800 N Point St"))
+        .city(String::from("San Francisco"))
+        .state(String::from("CA"))
+        .country(String::from("US"))
+        .build()
+        .unwrap();
+    client.business_match(&payload).unwrap();
+
+    let request_lines: Vec<String> = request_lines.lock().unwrap().clone();
+    assert!(request_lines[0].contains("locale=fr_fr"));
+    assert!(!request_lines[1].contains("locale"));
+}
+
+#[test]
+fn test_business_details_id_is_percent_encoded() {
+    let request_lines: Arc<Mutex<Vec<String>>> = Arc::default();
+    let recorded_request_lines: Arc<Mutex<Vec<String>>> = Arc::clone(&request_lines);
+    let base_url: String = spawn_server(move |request| {
+        let request_line: &str = request.lines().next().unwrap();
+        recorded_request_lines
+            .lock()
+            .unwrap()
+            .push(request_line.to_string());
+        let body: &str = r#"{"error":{"code":"BUSINESS_NOT_FOUND","description":"not found"}}"#;
+        ("404 Not Found", String::new(), body.to_string())
+    });
+    let client: BlockingYelpFusionClient = BlockingYelpFusionClient::builder("api_key")
+        .base_url(base_url)
+        .build()
+        .unwrap();
+
+    client.business_details("a/b?c", None).unwrap_err();
+
+    let request_lines: Vec<String> = request_lines.lock().unwrap().clone();
+    assert_eq!(vec!["get /businesses/a%2fb%3fc http/1.1"], request_lines);
+}